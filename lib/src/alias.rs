@@ -0,0 +1,110 @@
+use crate::deck::{Deck, Section};
+use crate::util::{CsvError, row};
+use csv::ReaderBuilder;
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+/// Maps the passcodes of alternate artworks to the passcode used in the card database.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AliasTable {
+    canonical: HashMap<u32, u32>, // alternate passcode -> canonical passcode
+}
+
+impl AliasTable {
+    pub fn insert(&mut self, alias: u32, id: u32) {
+        if alias != id {
+            self.canonical.insert(alias, id);
+        }
+    }
+
+    /// Parse a CSV file with the columns `alias,id`, failing on the first invalid row.
+    pub fn try_from_csv(data: &str) -> Result<AliasTable, CsvError> {
+        let mut rdr = ReaderBuilder::new().from_reader(data.as_bytes());
+        let mut table = AliasTable::new();
+
+        for result in rdr.records() {
+            let record = result.map_err(CsvError::read)?;
+            let number = |i: usize, column: &str| {
+                let value = record.get(i).unwrap_or_default().trim();
+                value
+                    .parse()
+                    .map_err(|_| CsvError::new(row(&record), column, value))
+            };
+            table.insert(number(0, "alias")?, number(1, "id")?);
+        }
+
+        Ok(table)
+    }
+}
+
+#[wasm_bindgen]
+impl AliasTable {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> AliasTable {
+        AliasTable::default()
+    }
+
+    /// Parse a CSV file with the columns `alias,id`, e.g. for a card database without aliases.
+    pub fn from_csv(data: &str) -> Result<AliasTable, JsError> {
+        Ok(AliasTable::try_from_csv(data)?)
+    }
+
+    /// The canonical passcode, passcodes without alias are returned unchanged.
+    pub fn canonical(&self, id: u32) -> u32 {
+        self.canonical.get(&id).copied().unwrap_or(id)
+    }
+
+    pub fn is_alias(&self, id: u32) -> bool {
+        self.canonical.contains_key(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.canonical.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.canonical.is_empty()
+    }
+
+    /// A copy of the deck with every passcode replaced by its canonical one,
+    /// copies of different artworks are merged.
+    pub fn canonical_deck(&self, deck: &Deck) -> Deck {
+        let mut result = Deck::new();
+        for section in Section::ALL {
+            for &(id, copies) in deck.entries(section) {
+                result.add(section, self.canonical(id), copies);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_csv() {
+        let table =
+            AliasTable::try_from_csv("alias,id\n89631140,89631139\n89631141,89631139\n").unwrap();
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.canonical(89631141), 89631139);
+        assert_eq!(table.canonical(89631139), 89631139);
+        assert!(!table.is_alias(89631139));
+
+        let deck = Deck::from_ids(&[89631139, 89631140, 89631141], &[], &[89631140]);
+        let deck = table.canonical_deck(&deck);
+        assert_eq!(deck.entries(Section::Main), &[(89631139, 3)]);
+        assert_eq!(deck.entries(Section::Side), &[(89631139, 1)]);
+    }
+
+    #[test]
+    fn test_invalid_rows() {
+        let error =
+            AliasTable::try_from_csv("alias,id\n89631140,89631139\n89631141,x\n").unwrap_err();
+        assert_eq!((error.row(), error.column(), error.value()), (3, "id", "x"));
+        let error = AliasTable::try_from_csv("alias,id\n89631140\n").unwrap_err();
+        assert_eq!((error.row(), error.column()), (2, "record"));
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BitSet {
     data: Vec<u64>, // one word stores 64 bits/monsters
     len: usize,     // number of bits/monsters
@@ -6,7 +6,7 @@ pub struct BitSet {
 
 impl BitSet {
     pub fn new(len: usize) -> Self {
-        let number_of_words = len + 64 / 64;
+        let number_of_words = len.div_ceil(64);
         Self {
             data: vec![0; number_of_words],
             len,
@@ -69,6 +69,15 @@ impl BitSet {
         result
     }
 
+    /// logical and not, the bits of self that are not set in other
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = Self::new(self.len);
+        for i in 0..self.data.len() {
+            result.data[i] = self.data[i] & !other.data[i];
+        }
+        result
+    }
+
     /// logical not
     pub fn not(&self) -> Self {
         let mut result = Self::new(self.len);
@@ -1,65 +1,90 @@
 use crate::bitset::BitSet;
-use crate::index::{BitSetIndex, MonsterIndex};
-use crate::monster::Monster;
-use itertools::Itertools;
+use crate::index::BitSetIndex;
+#[cfg(test)]
+use crate::index::MonsterIndex;
+use crate::monster::{Monster, Property};
+use crate::rule::{ConnectionRule, MatchCount};
+#[cfg(test)]
 use std::collections::HashSet;
 
-/// Finds bridge monsters using bitset intersections.
-///
-/// For each monster, its neighborhood is represented as a bitset over the global monster index.
-/// All neighborhood bitsets are intersected (`AND`), yielding exactly those monsters that appear
-/// in every neighborhood.
-///```
-/// // monsters with >= 1 matching property
-/// candidates =
-///       Index[Attribute][DARK]
-///     | Index[Level][4]
-///     | Index[Type][Fiend]
-///     | Index[ATK][1700]
-///     | Index[DEF][0]
-///
-/// // monsters with >= 2 matching properties
-/// exclude = (Index[Attribute][DARK] & Index[Level][4]) | ... | (Index[ATK][1700] & Index[DEF][0]) // 5*4/2 = 10 combinations
+/// Computes all monsters connected to the given monster under the rule.
 ///
-/// // exactly 1 matching property
-/// neighborhood = candidate & !exclude
+/// Only the participating properties are looked up. The bitsets `at_least[j]` of monsters
+/// sharing at least j of them are built one property at a time, every match count is then
+/// a combination of two of these sets.
+///```text
+/// at_least[j] = at_least[j] | (at_least[j - 1] & Index[property][value])
 ///
-/// // intersection of neighborhoods are bridges
-/// bridges = neighborhood(m1) & neighborhood(m2)
+/// exactly n  = at_least[n] & !at_least[n + 1]
+/// at least n = at_least[n]
+/// at most n  = !at_least[n + 1]
 /// ```
-pub fn search_bridge_bitset(monsters: &[&Monster], index: &BitSetIndex) -> Option<BitSet> {
-    monsters
+/// The bridges between monsters are the intersection (`AND`) of their neighborhoods.
+pub fn find_neighborhood_bitset(
+    monster: &Monster,
+    index: &BitSetIndex,
+    rule: &ConnectionRule,
+) -> BitSet {
+    // a "?" stat that never matches shares the stat with nobody
+    let empty = BitSet::new(index.len());
+    let unknown = index.unknown_stats;
+    let sets = rule
+        .properties()
         .iter()
-        .map(|m| find_neighborhood_bitset(m, index))
-        .reduce(|a, b| a.and(&b))
-}
+        .map(|property| match property {
+            Property::Attribute => index.by_attribute.get(monster.attribute()).unwrap(),
+            Property::Level => index.by_level.get(Some(monster.level())).unwrap(),
+            Property::Type => index.by_type.get(monster.r#type()).unwrap(),
+            Property::Atk => index
+                .by_atk
+                .connecting(monster.atk(), unknown)
+                .unwrap_or(&empty),
+            Property::Def => index
+                .by_def
+                .connecting(monster.def(), unknown)
+                .unwrap_or(&empty),
+        })
+        .collect::<Vec<_>>();
 
-pub fn find_neighborhood_bitset(monster: &Monster, index: &BitSetIndex) -> BitSet {
-    let sets = [
-        index.by_attribute.get(&monster.attribute()).unwrap(),
-        index.by_level.get(&monster.level()).unwrap(),
-        index.by_type.get(&monster.r#type()).unwrap(),
-        index.by_atk.get(&monster.atk()).unwrap(),
-        index.by_def.get(&monster.def()).unwrap(),
-    ];
+    // at_least[j] holds the monsters with >= j properties in common
+    let mut at_least = vec![BitSet::new(index.len()); sets.len() + 1];
+    at_least[0] = at_least[0].not();
+    for set in sets {
+        for j in (1..at_least.len()).rev() {
+            at_least[j] = at_least[j].or(&at_least[j - 1].and(set));
+        }
+    }
 
-    // >= 1 property in common
-    let candidates = sets
-        .iter()
-        .fold(BitSet::new(index.len()), |acc: BitSet, s| acc.or(s));
+    let at_least_n = |n: u32| {
+        at_least
+            .get(n as usize)
+            .cloned()
+            .unwrap_or_else(|| BitSet::new(index.len()))
+    };
 
-    // >= 2 properties in common
-    let exclude = sets
-        .iter()
-        .combinations(2)
-        .map(|pair| pair[0].and(&pair[1]))
-        .fold(BitSet::new(index.len()), |acc: BitSet, s| acc.or(&s));
+    match rule.count() {
+        MatchCount::Exactly(n) => at_least_n(n).and(&at_least_n(n + 1).not()),
+        MatchCount::AtLeast(n) => at_least_n(n),
+        MatchCount::AtMost(n) => at_least_n(n + 1).not(),
+    }
+}
 
-    // 1 property in common
-    candidates.and(&exclude.not())
+/// Computes the neighborhood of every monster, indexed like the monsters.
+///
+/// Each bitset holds one bit per monster, so the whole matrix takes `n * n / 8` bytes.
+pub fn compute_adjacency(
+    monsters: &[Monster],
+    index: &BitSetIndex,
+    rule: &ConnectionRule,
+) -> Vec<BitSet> {
+    monsters
+        .iter()
+        .map(|m| find_neighborhood_bitset(m, index, rule))
+        .collect()
 }
 
 /// Finds bridge monsters by computing the intersection of neighborhood sets.
+/// Only compiled for tests, as the reference the bitset search is checked against.
 ///
 /// Given at least two monsters, this function returns all monsters that appear
 /// in the neighborhood of every input monster, using a precomputed index for
@@ -68,6 +93,7 @@ pub fn find_neighborhood_bitset(monster: &Monster, index: &BitSetIndex) -> BitSe
 /// Neighborhoods are intersected incrementally to limit intermediate result
 /// sizes. Runtime is proportional to the total size of the neighborhoods,
 /// and memory usage is linear in the size of the intersection.
+#[cfg(test)]
 pub fn search_bridges<'a>(
     monsters: &[&Monster],
     index: &MonsterIndex<'a>,
@@ -78,9 +104,9 @@ pub fn search_bridges<'a>(
 
     let bridges = monsters
         .iter()
-        .map(|monster| find_neighborhood(monster, &index))
+        .map(|monster| find_neighborhood(monster, index))
         .fold(
-            find_neighborhood(monsters.first().unwrap(), &index),
+            find_neighborhood(monsters.first().unwrap(), index),
             |acc, neighbourhood| {
                 acc.intersection(&neighbourhood)
                     .copied()
@@ -94,13 +120,15 @@ pub fn search_bridges<'a>(
     Some(bridges)
 }
 
+#[cfg(test)]
 fn find_neighborhood<'a>(monster: &Monster, index: &MonsterIndex<'a>) -> HashSet<&'a Monster> {
+    let unknown = index.unknown_stats;
     [
-        index.by_attribute.get(&monster.attribute()).unwrap(),
+        index.by_attribute.get(monster.attribute()).unwrap(),
         index.by_level.get(&monster.level()).unwrap(),
-        index.by_type.get(&monster.r#type()).unwrap(),
-        index.by_atk.get(&monster.atk()).unwrap(),
-        index.by_def.get(&monster.def()).unwrap(),
+        index.by_type.get(monster.r#type()).unwrap(),
+        index.by_atk.get(&unknown.value(monster.atk())).unwrap(),
+        index.by_def.get(&unknown.value(monster.def())).unwrap(),
     ]
     .iter()
     .fold(HashSet::new(), |mut acc: HashSet<&Monster>, monsters| {
@@ -109,8 +137,8 @@ fn find_neighborhood<'a>(monster: &Monster, index: &MonsterIndex<'a>) -> HashSet
                 monster.attribute() == m.attribute(),
                 monster.level() == m.level(),
                 monster.r#type() == m.r#type(),
-                monster.atk() == m.atk(),
-                monster.def() == m.def(),
+                unknown.same(monster.atk(), m.atk()),
+                unknown.same(monster.def(), m.def()),
             ]
             .iter()
             .filter(|&eq| *eq)
@@ -128,11 +156,21 @@ fn find_neighborhood<'a>(monster: &Monster, index: &MonsterIndex<'a>) -> HashSet
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::monster::UnknownStats;
     use crate::util::parse_csv_file;
 
+    /// Bridges are the intersection of the neighborhoods of all monsters.
+    fn bridge_bitset(monsters: &[&Monster], index: &BitSetIndex) -> BitSet {
+        monsters
+            .iter()
+            .map(|m| find_neighborhood_bitset(m, index, &ConnectionRule::default()))
+            .reduce(|a, b| a.and(&b))
+            .unwrap()
+    }
+
     #[test]
     fn test_bridge_count() {
-        let monsters = parse_csv_file("testing_data.csv");
+        let monsters = parse_csv_file("testing_data.csv").unwrap();
         let index = MonsterIndex::new(&monsters);
         let bs_index = BitSetIndex::new(&monsters);
 
@@ -142,17 +180,12 @@ mod tests {
             .collect::<Vec<&Monster>>();
 
         assert_eq!(search_bridges(&query, &index).unwrap().len(), 3);
-        assert_eq!(
-            search_bridge_bitset(&query, &bs_index)
-                .unwrap()
-                .count_ones(),
-            3
-        );
+        assert_eq!(bridge_bitset(&query, &bs_index).count_ones(), 3);
     }
 
     #[test]
     fn test_multi_intersection() {
-        let monsters = parse_csv_file("testing_data.csv");
+        let monsters = parse_csv_file("testing_data.csv").unwrap();
         let index = MonsterIndex::new(&monsters);
         let bs_index = BitSetIndex::new(&monsters);
 
@@ -162,27 +195,87 @@ mod tests {
             .collect::<Vec<&Monster>>();
 
         assert_eq!(search_bridges(&query, &index).unwrap().len(), 1);
-        assert_eq!(
-            search_bridge_bitset(&query, &bs_index)
-                .unwrap()
-                .count_ones(),
-            1
-        );
+        assert_eq!(bridge_bitset(&query, &bs_index).count_ones(), 1);
     }
 
     #[test]
     fn test_unknown_atk_stat() {
-        let monsters = parse_csv_file("testing_data.csv");
-        let index = MonsterIndex::new(&monsters);
+        let monsters = parse_csv_file("testing_data.csv").unwrap();
+        let ids = |bridges: Vec<&Monster>| {
+            let mut ids = bridges.iter().map(|m| m.id()).collect::<Vec<_>>();
+            ids.sort();
+            ids
+        };
+        let bitset_ids = |bridges: BitSet| {
+            let mut ids = bridges
+                .ones()
+                .into_iter()
+                .map(|i| monsters[i].id())
+                .collect::<Vec<_>>();
+            ids.sort();
+            ids
+        };
+
+        // Gren Maju Da Eiza (FIRE 3 Fiend ?/?) and The Winged Dragon of Ra - Sphere Mode (?/?)
+        for (unknown, gren_ra, gren_veiler) in [
+            (UnknownStats::MatchEachOther, true, false),
+            (UnknownStats::NeverMatch, false, false),
+            (UnknownStats::AsZero, true, true),
+        ] {
+            let index = MonsterIndex::with_unknown_stats(&monsters, unknown);
+            let bs_index = BitSetIndex::with_unknown_stats(&monsters, unknown);
+            let atk_rule = ConnectionRule::exactly(vec![Property::Atk], 1);
+            let gren = index.by_id[&36584821];
+            let ra = index.by_id[&10000080];
+            let veiler = index.by_id[&97268402]; // 0/0
+
+            assert_eq!(atk_rule.connects(gren, ra, unknown), gren_ra);
+            assert_eq!(atk_rule.connects(gren, veiler, unknown), gren_veiler);
 
-        let result = search_bridges(
-            &[
-                index.by_id.get(&39931513_u32).unwrap(),
-                index.by_id.get(&36584821_u32).unwrap(), // Gren Maju Da Eiza (atk = None, def = None)
-            ],
-            &index,
-        );
+            for query in [
+                [39931513, 36584821],
+                [36584821, 10000080],
+                [36584821, 97268402],
+            ] {
+                let query = query.map(|id| index.by_id[&id]);
+                assert_eq!(
+                    ids(search_bridges(&query, &index).unwrap()),
+                    bitset_ids(bridge_bitset(&query, &bs_index)),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_neighborhood_rules() {
+        let monsters = parse_csv_file("testing_data.csv").unwrap();
+
+        let rules = [
+            ConnectionRule::default(),
+            ConnectionRule::exactly(
+                vec![Property::Attribute, Property::Level, Property::Type],
+                1,
+            ),
+            ConnectionRule::exactly(Property::ALL.to_vec(), 0),
+            ConnectionRule::at_least(Property::ALL.to_vec(), 2),
+            ConnectionRule::at_most(vec![Property::Atk, Property::Def], 1),
+            ConnectionRule::exactly(Property::ALL.to_vec(), 6),
+        ];
 
-        dbg!(&result.unwrap());
+        for unknown in [
+            UnknownStats::AsZero,
+            UnknownStats::NeverMatch,
+            UnknownStats::MatchEachOther,
+        ] {
+            let bs_index = BitSetIndex::with_unknown_stats(&monsters, unknown);
+            for rule in &rules {
+                for monster in &monsters {
+                    let neighborhood = find_neighborhood_bitset(monster, &bs_index, rule);
+                    for (i, other) in monsters.iter().enumerate() {
+                        assert_eq!(neighborhood.get(i), rule.connects(monster, other, unknown));
+                    }
+                }
+            }
+        }
     }
 }
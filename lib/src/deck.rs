@@ -0,0 +1,393 @@
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+pub const MAIN_DECK_MIN: u32 = 40;
+pub const MAIN_DECK_MAX: u32 = 60;
+pub const EXTRA_DECK_MAX: u32 = 15;
+pub const SIDE_DECK_MAX: u32 = 15;
+pub const MAX_COPIES: u32 = 3;
+
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Section {
+    Main,
+    Extra,
+    Side,
+}
+
+impl Section {
+    pub const ALL: [Section; 3] = [Section::Main, Section::Extra, Section::Side];
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Section::Main => "main",
+            Section::Extra => "extra",
+            Section::Side => "side",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A decklist storing the number of copies of every passcode per section.
+/// Passcodes keep the order in which they were first added.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Deck {
+    sections: [Vec<(u32, u32)>; 3], // (passcode, copies) for main, extra and side
+}
+
+impl Deck {
+    pub fn from_ids(main: &[u32], extra: &[u32], side: &[u32]) -> Deck {
+        let mut deck = Deck::default();
+        for (section, ids) in Section::ALL.into_iter().zip([main, extra, side]) {
+            for &id in ids {
+                deck.add(section, id, 1);
+            }
+        }
+        deck
+    }
+
+    /// (passcode, copies) of every card in the section.
+    pub fn entries(&self, section: Section) -> &[(u32, u32)] {
+        &self.sections[section as usize]
+    }
+
+    /// Remove every card of the section.
+    pub fn clear(&mut self, section: Section) {
+        self.sections[section as usize].clear();
+    }
+}
+
+#[wasm_bindgen]
+impl Deck {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Deck {
+        Deck::default()
+    }
+
+    /// All passcodes of the section, one entry per copy.
+    pub fn ids(&self, section: Section) -> Vec<u32> {
+        self.entries(section)
+            .iter()
+            .flat_map(|&(id, copies)| std::iter::repeat_n(id, copies as usize))
+            .collect()
+    }
+
+    /// Passcodes of the section without duplicates.
+    pub fn unique_ids(&self, section: Section) -> Vec<u32> {
+        self.entries(section).iter().map(|&(id, _)| id).collect()
+    }
+
+    /// Copies of every passcode, in the order of `unique_ids`.
+    pub fn counts(&self, section: Section) -> Vec<u32> {
+        self.entries(section)
+            .iter()
+            .map(|&(_, copies)| copies)
+            .collect()
+    }
+
+    /// Copies of the passcode in the section.
+    pub fn count(&self, section: Section, id: u32) -> u32 {
+        self.entries(section)
+            .iter()
+            .find(|(i, _)| *i == id)
+            .map_or(0, |&(_, copies)| copies)
+    }
+
+    /// Copies of the passcode over all sections.
+    pub fn copies(&self, id: u32) -> u32 {
+        Section::ALL
+            .into_iter()
+            .map(|section| self.count(section, id))
+            .sum()
+    }
+
+    /// Number of cards in the section.
+    pub fn len(&self, section: Section) -> u32 {
+        self.entries(section)
+            .iter()
+            .map(|&(_, copies)| copies)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections.iter().all(|entries| entries.is_empty())
+    }
+
+    pub fn add(&mut self, section: Section, id: u32, copies: u32) {
+        let entries = &mut self.sections[section as usize];
+        match entries.iter_mut().find(|(i, _)| *i == id) {
+            Some((_, count)) => *count += copies,
+            None if copies > 0 => entries.push((id, copies)),
+            None => {}
+        }
+    }
+
+    /// Removes up to `copies` copies, returns how many were removed.
+    pub fn remove(&mut self, section: Section, id: u32, copies: u32) -> u32 {
+        let entries = &mut self.sections[section as usize];
+        let Some(position) = entries.iter().position(|(i, _)| *i == id) else {
+            return 0;
+        };
+        let removed = copies.min(entries[position].1);
+        entries[position].1 -= removed;
+        if entries[position].1 == 0 {
+            entries.remove(position);
+        }
+        removed
+    }
+
+    /// Every change needed to turn this deck into `other`.
+    pub fn diff(&self, other: &Deck) -> Vec<DeckChange> {
+        let mut changes = vec![];
+        for section in Section::ALL {
+            let mut ids = self.unique_ids(section);
+            for id in other.unique_ids(section) {
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+            for id in ids {
+                let (from, to) = (self.count(section, id), other.count(section, id));
+                if from != to {
+                    changes.push(DeckChange {
+                        id,
+                        section,
+                        from,
+                        to,
+                    });
+                }
+            }
+        }
+        changes
+    }
+
+    /// Checks section sizes and the copy limit, returns every violated rule.
+    pub fn validate(&self) -> Vec<DeckIssue> {
+        let mut issues = vec![];
+
+        let main = self.len(Section::Main);
+        if main < MAIN_DECK_MIN {
+            issues.push(DeckIssue::new(DeckIssueKind::MainDeckTooSmall, None, main));
+        }
+        if main > MAIN_DECK_MAX {
+            issues.push(DeckIssue::new(DeckIssueKind::MainDeckTooLarge, None, main));
+        }
+        let extra = self.len(Section::Extra);
+        if extra > EXTRA_DECK_MAX {
+            issues.push(DeckIssue::new(
+                DeckIssueKind::ExtraDeckTooLarge,
+                None,
+                extra,
+            ));
+        }
+        let side = self.len(Section::Side);
+        if side > SIDE_DECK_MAX {
+            issues.push(DeckIssue::new(DeckIssueKind::SideDeckTooLarge, None, side));
+        }
+
+        let mut seen = vec![];
+        for section in Section::ALL {
+            for &(id, _) in self.entries(section) {
+                if seen.contains(&id) {
+                    continue;
+                }
+                seen.push(id);
+                let copies = self.copies(id);
+                if copies > MAX_COPIES {
+                    issues.push(DeckIssue::new(
+                        DeckIssueKind::TooManyCopies,
+                        Some(id),
+                        copies,
+                    ));
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+/// The number of copies of a passcode in a section differs between two decks.
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DeckChange {
+    id: u32,
+    section: Section,
+    from: u32,
+    to: u32,
+}
+
+#[wasm_bindgen]
+impl DeckChange {
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn section(&self) -> Section {
+        self.section
+    }
+
+    /// copies in the original deck
+    #[wasm_bindgen(getter)]
+    pub fn from(&self) -> u32 {
+        self.from
+    }
+
+    /// copies in the other deck
+    #[wasm_bindgen(getter)]
+    pub fn to(&self) -> u32 {
+        self.to
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DeckIssueKind {
+    MainDeckTooSmall,
+    MainDeckTooLarge,
+    ExtraDeckTooLarge,
+    SideDeckTooLarge,
+    TooManyCopies,
+}
+
+/// A violated deck building rule.
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DeckIssue {
+    kind: DeckIssueKind,
+    id: Option<u32>, // the passcode, for copy limit issues
+    count: u32,      // the number of cards or copies that violates the rule
+}
+
+impl DeckIssue {
+    fn new(kind: DeckIssueKind, id: Option<u32>, count: u32) -> Self {
+        DeckIssue { kind, id, count }
+    }
+}
+
+impl fmt::Display for DeckIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            DeckIssueKind::MainDeckTooSmall => write!(
+                f,
+                "Main Deck has {} cards, at least {} are required",
+                self.count, MAIN_DECK_MIN
+            ),
+            DeckIssueKind::MainDeckTooLarge => write!(
+                f,
+                "Main Deck has {} cards, at most {} are allowed",
+                self.count, MAIN_DECK_MAX
+            ),
+            DeckIssueKind::ExtraDeckTooLarge => write!(
+                f,
+                "Extra Deck has {} cards, at most {} are allowed",
+                self.count, EXTRA_DECK_MAX
+            ),
+            DeckIssueKind::SideDeckTooLarge => write!(
+                f,
+                "Side Deck has {} cards, at most {} are allowed",
+                self.count, SIDE_DECK_MAX
+            ),
+            DeckIssueKind::TooManyCopies => write!(
+                f,
+                "{} copies of {}, at most {} are allowed",
+                self.count,
+                self.id.unwrap_or_default(),
+                MAX_COPIES
+            ),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl DeckIssue {
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> DeckIssueKind {
+        self.kind
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> Option<u32> {
+        self.id
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts() {
+        let mut deck = Deck::from_ids(&[1, 2, 1, 3, 1], &[10], &[2]);
+        assert_eq!(deck.len(Section::Main), 5);
+        assert_eq!(deck.unique_ids(Section::Main), vec![1, 2, 3]);
+        assert_eq!(deck.counts(Section::Main), vec![3, 1, 1]);
+        assert_eq!(deck.ids(Section::Main), vec![1, 1, 1, 2, 3]);
+        assert_eq!(deck.copies(2), 2);
+
+        assert_eq!(deck.remove(Section::Main, 1, 5), 3);
+        assert_eq!(deck.count(Section::Main, 1), 0);
+        assert_eq!(deck.unique_ids(Section::Main), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = Deck::from_ids(&[1, 1, 2], &[], &[3]);
+        let after = Deck::from_ids(&[1, 4], &[], &[3]);
+        let changes = before.diff(&after);
+        assert_eq!(
+            changes,
+            vec![
+                DeckChange {
+                    id: 1,
+                    section: Section::Main,
+                    from: 2,
+                    to: 1
+                },
+                DeckChange {
+                    id: 2,
+                    section: Section::Main,
+                    from: 1,
+                    to: 0
+                },
+                DeckChange {
+                    id: 4,
+                    section: Section::Main,
+                    from: 0,
+                    to: 1
+                },
+            ]
+        );
+        assert!(before.diff(&before).is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        let main = (0..40).map(|i| i / 3).collect::<Vec<u32>>();
+        let deck = Deck::from_ids(&main, &[], &[]);
+        assert!(deck.validate().is_empty());
+
+        let deck = Deck::from_ids(&main[..39], &[], &[0]);
+        let issues = deck.validate();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].kind, DeckIssueKind::MainDeckTooSmall);
+        assert_eq!(issues[1].kind, DeckIssueKind::TooManyCopies);
+        assert_eq!(issues[1].id, Some(0));
+        assert_eq!(issues[1].count, 4);
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum IgnoreReason {
+    /// the passcode is unknown
+    NotInDatabase,
+    /// a Spell, Trap or Extra Deck monster, Small World can not search it
+    NotMainDeckMonster,
+    /// an alternate artwork, the query used the canonical passcode instead
+    AliasResolved,
+    /// a monster past the 128 distinct monsters the odds of a deck are computed for
+    OverMonsterLimit,
+}
+
+/// A passcode of a query that was ignored or replaced.
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct IgnoredId {
+    id: u32,
+    reason: IgnoreReason,
+    canonical: Option<u32>, // the passcode used instead, for aliases
+}
+
+impl IgnoredId {
+    pub(crate) fn new(id: u32, reason: IgnoreReason, canonical: Option<u32>) -> Self {
+        IgnoredId {
+            id,
+            reason,
+            canonical,
+        }
+    }
+}
+
+impl fmt::Display for IgnoredId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.reason {
+            IgnoreReason::NotInDatabase => write!(f, "{} is not in the database", self.id),
+            IgnoreReason::NotMainDeckMonster => {
+                write!(f, "{} is not a Main Deck monster", self.id)
+            }
+            IgnoreReason::AliasResolved => write!(
+                f,
+                "{} is an alternate artwork of {}",
+                self.id,
+                self.canonical.unwrap_or_default()
+            ),
+            IgnoreReason::OverMonsterLimit => write!(
+                f,
+                "{} exceeds the limit of distinct monsters in a deck",
+                self.id
+            ),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl IgnoredId {
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn reason(&self) -> IgnoreReason {
+        self.reason
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn canonical(&self) -> Option<u32> {
+        self.canonical
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Every passcode of a query that did not map directly to a monster of the database.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Diagnostics {
+    ignored: Vec<IgnoredId>,
+}
+
+impl Diagnostics {
+    /// Adds the entry, unless the passcode is already listed.
+    pub(crate) fn push(&mut self, entry: IgnoredId) {
+        if !self.ignored.iter().any(|e| e.id == entry.id) {
+            self.ignored.push(entry);
+        }
+    }
+
+    pub fn entries(&self) -> &[IgnoredId] {
+        &self.ignored
+    }
+}
+
+#[wasm_bindgen]
+impl Diagnostics {
+    /// ignored and replaced passcodes, in the order they appeared in the query
+    #[wasm_bindgen(getter)]
+    pub fn ignored(&self) -> Vec<IgnoredId> {
+        self.ignored.clone()
+    }
+
+    /// whether every passcode was used as given
+    pub fn is_empty(&self) -> bool {
+        self.ignored.is_empty()
+    }
+
+    /// passcodes that were left out of the query, aliases are not counted
+    pub fn dropped_ids(&self) -> Vec<u32> {
+        self.ignored
+            .iter()
+            .filter(|e| e.reason != IgnoreReason::AliasResolved)
+            .map(|e| e.id)
+            .collect()
+    }
+}
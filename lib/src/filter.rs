@@ -0,0 +1,647 @@
+//! A small query language to filter monsters, e.g.
+//!
+//! ```text
+//! attr:DARK,LIGHT lvl:1..4 type:Fiend atk>=1500 def=0 -name:"Ash"
+//! ```
+//!
+//! Terms are separated by whitespace and all of them have to match, a leading `-` negates a term.
+//! Comma separated values match any of them. Level, ATK and DEF accept single values, ranges
+//! (`1..4`, `..4`, `5..`) and comparisons (`>=`, `>`, `<=`, `<`), ATK and DEF also `?` for unknown.
+//! Attributes and types the library does not know yet are accepted if the loaded card database
+//! has them, when the filter is parsed by the searcher (`parse_filter`), e.g. `type:"creator god"`.
+//! Names match case-insensitively, `name:` anywhere in the name and `name=` exactly.
+//! `frame:` accepts `normal`, `effect`, `ritual` (each including its Pendulum frame), `pendulum`
+//! and exact frames like `normal_pendulum`, `ability:` accepts Tuner, Flip, Gemini, Spirit, Toon
+//! and Union, e.g. `frame:normal -frame:pendulum ability:tuner`.
+//! A term without field searches the name.
+
+use crate::bitset::BitSet;
+use crate::index::{BitSetIndex, StatIndex};
+use crate::monster::{Ability, Attribute, Frame, Monster, Type};
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    Attribute(Vec<Attribute>),
+    Type(Vec<Type>),
+    Level(Vec<Range>),
+    Atk(Vec<Range>),
+    Def(Vec<Range>),
+    Frame(Vec<Frame>),
+    Ability(Vec<Ability>),
+    Name { text: String, exact: bool },
+}
+
+/// Inclusive range of stat values, `unknown` matches "?" stats.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Range {
+    min: u32,
+    max: u32,
+    unknown: bool,
+}
+
+impl Range {
+    const UNKNOWN: Range = Range {
+        min: 1,
+        max: 0,
+        unknown: true,
+    };
+
+    fn new(min: u32, max: u32) -> Range {
+        Range {
+            min,
+            max,
+            unknown: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Term {
+    negated: bool,
+    condition: Condition,
+}
+
+/// A parsed filter expression, see the module documentation for the syntax.
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardFilter {
+    terms: Vec<Term>,
+}
+
+#[wasm_bindgen]
+impl CardFilter {
+    pub fn parse(query: &str) -> Result<CardFilter, FilterError> {
+        Parser::new(query).filter()
+    }
+
+    /// whether the filter has no terms and matches every monster
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl CardFilter {
+    /// Like `parse`, but also accepts the attributes and types of the index this version
+    /// does not know, compared case-insensitively with the card database.
+    pub(crate) fn parse_with_index(
+        query: &str,
+        index: &BitSetIndex,
+    ) -> Result<CardFilter, FilterError> {
+        let mut parser = Parser::new(query);
+        parser.unknown_attributes = index
+            .by_attribute
+            .keys()
+            .filter(|a| a.is_unknown())
+            .cloned()
+            .collect();
+        parser.unknown_types = index
+            .by_type
+            .keys()
+            .filter(|t| t.is_unknown())
+            .cloned()
+            .collect();
+        parser.filter()
+    }
+
+    /// The set of monsters matching every term.
+    pub(crate) fn evaluate(&self, monsters: &[Monster], index: &BitSetIndex) -> BitSet {
+        let all = BitSet::new(index.len()).not();
+        self.terms.iter().fold(all, |result, term| {
+            let matches = term.condition.evaluate(monsters, index);
+            if term.negated {
+                result.and(&matches.not())
+            } else {
+                result.and(&matches)
+            }
+        })
+    }
+}
+
+impl Condition {
+    fn evaluate(&self, monsters: &[Monster], index: &BitSetIndex) -> BitSet {
+        let empty = BitSet::new(index.len());
+        match self {
+            Condition::Attribute(attributes) => attributes
+                .iter()
+                .filter_map(|a| index.by_attribute.get(a))
+                .fold(empty, |result, set| result.or(set)),
+            Condition::Type(types) => types
+                .iter()
+                .filter_map(|t| index.by_type.get(t))
+                .fold(empty, |result, set| result.or(set)),
+            Condition::Level(ranges) => stat_ranges(&index.by_level, ranges),
+            Condition::Atk(ranges) => stat_ranges(&index.by_atk, ranges),
+            Condition::Def(ranges) => stat_ranges(&index.by_def, ranges),
+            Condition::Frame(frames) => frames
+                .iter()
+                .filter_map(|f| index.by_frame.get(f))
+                .fold(empty, |result, set| result.or(set)),
+            Condition::Ability(abilities) => abilities
+                .iter()
+                .filter_map(|a| index.by_ability.get(a))
+                .fold(empty, |result, set| result.or(set)),
+            Condition::Name { text, exact } => {
+                let text = text.to_lowercase();
+                let mut result = empty;
+                for (i, m) in monsters.iter().enumerate() {
+                    let name = m.name().to_lowercase();
+                    if (*exact && name == text) || (!*exact && name.contains(&text)) {
+                        result.set(i);
+                    }
+                }
+                result
+            }
+        }
+    }
+}
+
+/// Union of the ranges, each one a range query on the index.
+fn stat_ranges(index: &StatIndex, ranges: &[Range]) -> BitSet {
+    let empty = BitSet::new(index.unknown().len());
+    ranges.iter().fold(empty, |result, range| {
+        let mut matches = result.or(&index.range(range.min, range.max));
+        if range.unknown {
+            matches = matches.or(index.unknown());
+        }
+        matches
+    })
+}
+
+/// A filter expression that could not be parsed.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FilterError {
+    position: u32,
+    message: String,
+}
+
+impl FilterError {
+    fn new(position: usize, message: impl Into<String>) -> FilterError {
+        FilterError {
+            position: position as u32,
+            message: message.into(),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl FilterError {
+    /// character offset in the query where the error was found
+    #[wasm_bindgen(getter)]
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for FilterError {}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Operator {
+    Colon,
+    Equal,
+    Greater,
+    GreaterEqual,
+    Less,
+    LessEqual,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    unknown_attributes: Vec<Attribute>, // unknown values of the card database that are accepted
+    unknown_types: Vec<Type>,
+}
+
+impl Parser {
+    fn new(query: &str) -> Parser {
+        Parser {
+            chars: query.chars().collect(),
+            position: 0,
+            unknown_attributes: vec![],
+            unknown_types: vec![],
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn filter(&mut self) -> Result<CardFilter, FilterError> {
+        let mut terms = vec![];
+        loop {
+            self.skip_whitespace();
+            if self.peek().is_none() {
+                return Ok(CardFilter { terms });
+            }
+            terms.push(self.term()?);
+        }
+    }
+
+    fn term(&mut self) -> Result<Term, FilterError> {
+        let negated = self.eat('-');
+        let start = self.position;
+
+        if self.peek() == Some('"') {
+            let text = self.quoted()?;
+            return Ok(Term {
+                negated,
+                condition: Condition::Name { text, exact: false },
+            });
+        }
+
+        let word = self.word();
+        let operator_start = self.position;
+        let Some(operator) = self.operator() else {
+            if word.is_empty() {
+                return Err(FilterError::new(start, "expected a filter"));
+            }
+            return Ok(Term {
+                negated,
+                condition: Condition::Name {
+                    text: word,
+                    exact: false,
+                },
+            });
+        };
+
+        let condition = match word.to_lowercase().as_str() {
+            "attr" | "attribute" => {
+                let unknown = std::mem::take(&mut self.unknown_attributes);
+                let attributes = self.list(operator, operator_start, |value| {
+                    value
+                        .to_uppercase()
+                        .parse()
+                        .ok()
+                        .or_else(|| find_unknown(&unknown, value))
+                });
+                self.unknown_attributes = unknown;
+                Condition::Attribute(attributes?)
+            }
+            "type" => {
+                let unknown = std::mem::take(&mut self.unknown_types);
+                let types = self.list(operator, operator_start, |value| {
+                    capitalize(value)
+                        .parse()
+                        .ok()
+                        .or_else(|| find_unknown(&unknown, value))
+                });
+                self.unknown_types = unknown;
+                Condition::Type(types?)
+            }
+            "lvl" | "level" => Condition::Level(self.ranges(operator, false)?),
+            "atk" => Condition::Atk(self.ranges(operator, true)?),
+            "def" => Condition::Def(self.ranges(operator, true)?),
+            "frame" => Condition::Frame(self.list(operator, operator_start, frames)?.concat()),
+            "ability" => Condition::Ability(self.list(operator, operator_start, |value| {
+                capitalize(value).parse().ok()
+            })?),
+            "name" => {
+                let exact = match operator {
+                    Operator::Colon => false,
+                    Operator::Equal => true,
+                    _ => {
+                        return Err(FilterError::new(
+                            operator_start,
+                            "names can not be compared",
+                        ));
+                    }
+                };
+                let (_, text) = self.value()?;
+                Condition::Name { text, exact }
+            }
+            _ => return Err(FilterError::new(start, format!("unknown field '{}'", word))),
+        };
+
+        Ok(Term { negated, condition })
+    }
+
+    /// Characters up to the next whitespace, operator, comma or quote.
+    fn word(&mut self) -> String {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| !c.is_whitespace() && !":=<>,\"".contains(c))
+        {
+            self.position += 1;
+        }
+        self.chars[start..self.position].iter().collect()
+    }
+
+    fn quoted(&mut self) -> Result<String, FilterError> {
+        let start = self.position;
+        self.eat('"');
+        let text_start = self.position;
+        while self.peek().is_some_and(|c| c != '"') {
+            self.position += 1;
+        }
+        if !self.eat('"') {
+            return Err(FilterError::new(start, "unterminated quote"));
+        }
+        Ok(self.chars[text_start..self.position - 1].iter().collect())
+    }
+
+    fn operator(&mut self) -> Option<Operator> {
+        if self.eat(':') {
+            Some(Operator::Colon)
+        } else if self.eat('=') {
+            Some(Operator::Equal)
+        } else if self.eat('>') {
+            Some(match self.eat('=') {
+                true => Operator::GreaterEqual,
+                false => Operator::Greater,
+            })
+        } else if self.eat('<') {
+            Some(match self.eat('=') {
+                true => Operator::LessEqual,
+                false => Operator::Less,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// A single word or quoted value, with its position.
+    fn value(&mut self) -> Result<(usize, String), FilterError> {
+        let start = self.position;
+        let value = match self.peek() {
+            Some('"') => self.quoted()?,
+            _ => self.word(),
+        };
+        if value.is_empty() {
+            return Err(FilterError::new(start, "expected a value"));
+        }
+        Ok((start, value))
+    }
+
+    /// Comma separated values.
+    fn values(&mut self) -> Result<Vec<(usize, String)>, FilterError> {
+        let mut values = vec![self.value()?];
+        while self.eat(',') {
+            values.push(self.value()?);
+        }
+        Ok(values)
+    }
+
+    fn list<T>(
+        &mut self,
+        operator: Operator,
+        operator_start: usize,
+        parse: impl Fn(&str) -> Option<T>,
+    ) -> Result<Vec<T>, FilterError> {
+        if !matches!(operator, Operator::Colon | Operator::Equal) {
+            return Err(FilterError::new(
+                operator_start,
+                "only ':' and '=' are allowed here",
+            ));
+        }
+        self.values()?
+            .into_iter()
+            .map(|(position, value)| {
+                parse(&value)
+                    .ok_or_else(|| FilterError::new(position, format!("unknown value '{}'", value)))
+            })
+            .collect()
+    }
+
+    fn ranges(
+        &mut self,
+        operator: Operator,
+        allow_unknown: bool,
+    ) -> Result<Vec<Range>, FilterError> {
+        if matches!(operator, Operator::Colon | Operator::Equal) {
+            return self
+                .values()?
+                .into_iter()
+                .map(|(position, value)| parse_range(position, &value, allow_unknown))
+                .collect();
+        }
+
+        let (position, value) = self.value()?;
+        let n = parse_number(position, &value)?;
+        let range = match operator {
+            Operator::Greater => Range::new(n.saturating_add(1), u32::MAX),
+            Operator::GreaterEqual => Range::new(n, u32::MAX),
+            Operator::Less if n == 0 => Range::new(1, 0),
+            Operator::Less => Range::new(0, n - 1),
+            _ => Range::new(0, n),
+        };
+        Ok(vec![range])
+    }
+}
+
+fn parse_number(position: usize, value: &str) -> Result<u32, FilterError> {
+    value
+        .parse()
+        .map_err(|_| FilterError::new(position, format!("expected a number, found '{}'", value)))
+}
+
+/// `n`, `a..b`, `a..`, `..b` or `?`
+fn parse_range(position: usize, value: &str, allow_unknown: bool) -> Result<Range, FilterError> {
+    if value == "?" {
+        return match allow_unknown {
+            true => Ok(Range::UNKNOWN),
+            false => Err(FilterError::new(
+                position,
+                "'?' is only allowed for ATK and DEF",
+            )),
+        };
+    }
+    let Some((min, max)) = value.split_once("..") else {
+        let n = parse_number(position, value)?;
+        return Ok(Range::new(n, n));
+    };
+    let min = match min {
+        "" => 0,
+        min => parse_number(position, min)?,
+    };
+    let max = match max {
+        "" => u32::MAX,
+        max => parse_number(position + value.find("..").unwrap() + 2, max)?,
+    };
+    Ok(Range::new(min, max))
+}
+
+/// The frames matched by a `frame:` value, `normal` also matches Normal Pendulum monsters.
+fn frames(value: &str) -> Option<Vec<Frame>> {
+    let value = value.to_lowercase();
+    let frames = Frame::ALL
+        .into_iter()
+        .filter(|frame| match value.as_str() {
+            "pendulum" => frame.is_pendulum(),
+            value => frame.base().to_string() == value || frame.to_string() == value,
+        })
+        .collect::<Vec<_>>();
+    Some(frames).filter(|frames| !frames.is_empty())
+}
+
+/// "sea serpent" -> "Sea Serpent", "beast-warrior" -> "Beast-Warrior"
+/// The unknown value of the card database written like `value`, ignoring case.
+fn find_unknown<T: fmt::Display + Clone>(unknown: &[T], value: &str) -> Option<T> {
+    unknown
+        .iter()
+        .find(|u| u.to_string().eq_ignore_ascii_case(value))
+        .cloned()
+}
+
+fn capitalize(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut start = true;
+    for c in value.chars() {
+        if start {
+            result.extend(c.to_uppercase());
+        } else {
+            result.extend(c.to_lowercase());
+        }
+        start = c == ' ' || c == '-';
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{load_csv, parse_csv_file};
+
+    fn names(query: &str) -> Vec<String> {
+        let monsters = parse_csv_file("testing_data.csv").unwrap();
+        let index = BitSetIndex::new(&monsters);
+        let filter = CardFilter::parse(query).unwrap();
+        filter
+            .evaluate(&monsters, &index)
+            .ones()
+            .into_iter()
+            .map(|i| monsters[i].name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let filter = CardFilter::parse("attr:dark,LIGHT lvl:1..4 atk>=1500 -name:\"Ash\"").unwrap();
+        assert_eq!(
+            filter.terms,
+            vec![
+                Term {
+                    negated: false,
+                    condition: Condition::Attribute(vec![Attribute::DARK, Attribute::LIGHT]),
+                },
+                Term {
+                    negated: false,
+                    condition: Condition::Level(vec![Range::new(1, 4)]),
+                },
+                Term {
+                    negated: false,
+                    condition: Condition::Atk(vec![Range::new(1500, u32::MAX)]),
+                },
+                Term {
+                    negated: true,
+                    condition: Condition::Name {
+                        text: "Ash".into(),
+                        exact: false,
+                    },
+                },
+            ]
+        );
+        assert!(CardFilter::parse("  ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |query: &str| CardFilter::parse(query).unwrap_err().position();
+        assert_eq!(error("lvl:4 colour:red"), 6);
+        assert_eq!(error("attr:DARK,PINK"), 10);
+        assert_eq!(error("atk>=high"), 5);
+        assert_eq!(error("def:100..x"), 9);
+        assert_eq!(error("lvl:?"), 4);
+        assert_eq!(error("name:\"Ash"), 5);
+        assert_eq!(error("type>=Fiend"), 4);
+        assert_eq!(error("atk:"), 4);
+        assert_eq!(error("frame:link"), 6);
+        assert_eq!(error("ability:tuner,synchro"), 14);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(names("type:fish atk<1000"), names("type:Fish atk:..999"));
+        for name in names("attr:WATER,EARTH lvl:3..4 -type:Fish") {
+            assert!(!names("type:Fish").contains(&name));
+        }
+        assert_eq!(names("atk=?").len(), 2);
+        assert_eq!(names("name=\"7 colored fish\""), vec!["7 Colored Fish"]);
+        assert_eq!(names("lacooda"), vec!["3-Hump Lacooda"]);
+        assert!(names("atk>=1500 def=0").contains(&"Primite Dragon Ether Beryl".to_string()));
+        assert_eq!(names("").len(), 23);
+    }
+
+    #[test]
+    fn test_frames() {
+        let filter = CardFilter::parse("frame:normal ability:TUNER,toon").unwrap();
+        assert_eq!(
+            filter.terms[0].condition,
+            Condition::Frame(vec![Frame::Normal, Frame::NormalPendulum])
+        );
+        assert_eq!(
+            filter.terms[1].condition,
+            Condition::Ability(vec![Ability::Tuner, Ability::Toon])
+        );
+
+        let monsters = load_csv(
+            "id,name,attribute,level,type,atk,def,frame,abilities\n\
+            89631139,Blue-Eyes White Dragon,LIGHT,8,Dragon,3000,2500,normal,\n\
+            14558127,Ash Blossom & Joyous Spring,FIRE,3,Zombie,0,1800,effect,Tuner\n\
+            32134638,Dinomist Rex,WATER,6,Dinosaur,2400,1200,effect_pendulum,\n\
+            17947697,Maiden of White,LIGHT,1,Spellcaster,0,0,normal,Tuner\n\
+            46986414,Dark Magician,DARK,7,Spellcaster,2500,2100,normal,\n\
+            2,Ritual Pendulum Monster,WIND,1,Winged Beast,0,0,ritual_pendulum,\n",
+            false,
+        )
+        .unwrap()
+        .monsters;
+        let index = BitSetIndex::new(&monsters);
+        let ids = |query: &str| {
+            CardFilter::parse(query)
+                .unwrap()
+                .evaluate(&monsters, &index)
+                .ones()
+                .into_iter()
+                .map(|i| monsters[i].id())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids("frame:normal"), [89631139, 17947697, 46986414]);
+        assert_eq!(ids("frame:normal -ability:tuner"), [89631139, 46986414]);
+        assert_eq!(ids("frame:pendulum"), [32134638, 2]);
+        assert_eq!(ids("frame:effect_pendulum"), [32134638]);
+        assert_eq!(
+            ids("-frame:ritual"),
+            [89631139, 14558127, 32134638, 17947697, 46986414]
+        );
+        assert_eq!(ids("ability:tuner"), [14558127, 17947697]);
+        assert!(ids("ability:flip").is_empty());
+    }
+}
@@ -1,56 +1,67 @@
 use crate::bitset::BitSet;
-use crate::monster::{Attribute, Monster, Type};
-use std::collections::{HashMap, HashSet};
+use crate::monster::{Ability, Attribute, Frame, Monster, Type, UnknownStats};
+#[cfg(test)]
+use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap};
 
 /// A bitset based index
 pub struct BitSetIndex {
     len: usize,
     pub by_attribute: HashMap<Attribute, BitSet>,
-    pub by_level: HashMap<u32, BitSet>,
+    pub by_level: StatIndex,
     pub by_type: HashMap<Type, BitSet>,
-    pub by_atk: HashMap<Option<u32>, BitSet>,
-    pub by_def: HashMap<Option<u32>, BitSet>,
+    pub by_atk: StatIndex,
+    pub by_def: StatIndex,
+    pub by_frame: HashMap<Frame, BitSet>,
+    pub by_ability: HashMap<Ability, BitSet>,
+    pub unknown_stats: UnknownStats, // how "?" ATK/DEF connect
 }
 
 impl BitSetIndex {
-    pub fn new(monsters: &Vec<Monster>) -> BitSetIndex {
+    pub fn new(monsters: &[Monster]) -> BitSetIndex {
+        BitSetIndex::with_unknown_stats(monsters, UnknownStats::default())
+    }
+
+    pub fn with_unknown_stats(monsters: &[Monster], unknown_stats: UnknownStats) -> BitSetIndex {
         let len = monsters.len();
         let mut by_attribute: HashMap<Attribute, BitSet> = HashMap::new();
-        let mut by_level: HashMap<u32, BitSet> = HashMap::new();
         let mut by_type: HashMap<Type, BitSet> = HashMap::new();
-        let mut by_atk: HashMap<Option<u32>, BitSet> = HashMap::new();
-        let mut by_def: HashMap<Option<u32>, BitSet> = HashMap::new();
+        let mut by_frame: HashMap<Frame, BitSet> = HashMap::new();
+        let mut by_ability: HashMap<Ability, BitSet> = HashMap::new();
 
         for (i, m) in monsters.iter().enumerate() {
             by_attribute
-                .entry(m.attribute())
-                .or_insert_with(|| BitSet::new(len))
-                .set(i);
-            by_level
-                .entry(m.level())
+                .entry(m.attribute().clone())
                 .or_insert_with(|| BitSet::new(len))
                 .set(i);
             by_type
-                .entry(m.r#type())
-                .or_insert_with(|| BitSet::new(len))
-                .set(i);
-            by_atk
-                .entry(m.atk())
-                .or_insert_with(|| BitSet::new(len))
-                .set(i);
-            by_def
-                .entry(m.def())
+                .entry(m.r#type().clone())
                 .or_insert_with(|| BitSet::new(len))
                 .set(i);
+            if let Some(frame) = m.details().frame {
+                by_frame
+                    .entry(frame)
+                    .or_insert_with(|| BitSet::new(len))
+                    .set(i);
+            }
+            for &ability in m.abilities() {
+                by_ability
+                    .entry(ability)
+                    .or_insert_with(|| BitSet::new(len))
+                    .set(i);
+            }
         }
 
         BitSetIndex {
             len,
             by_attribute,
-            by_level,
+            by_level: StatIndex::new(monsters.iter().map(|m| Some(m.level()))),
             by_type,
-            by_atk,
-            by_def,
+            by_atk: StatIndex::new(monsters.iter().map(|m| m.atk())),
+            by_def: StatIndex::new(monsters.iter().map(|m| m.def())),
+            by_frame,
+            by_ability,
+            unknown_stats,
         }
     }
 
@@ -59,7 +70,115 @@ impl BitSetIndex {
     }
 }
 
-/// A simple inverted index structure providing multiple lookups for monsters.
+/// A numeric stat index that answers exact and range queries.
+///
+/// The distinct known values are sorted and `at_most[i]` holds every monster with a value
+/// of at most `values[i]`, so any range is the difference of two sets and takes O(words).
+/// Unknown ("?") stats are kept apart and never part of a range.
+pub struct StatIndex {
+    len: usize,
+    values: Vec<u32>,
+    exact: Vec<BitSet>,
+    at_most: Vec<BitSet>,
+    unknown: BitSet,
+    zero_or_unknown: BitSet, // "?" read as 0
+}
+
+impl StatIndex {
+    /// Builds the index from the stat of every monster, in monster order.
+    pub fn new(stats: impl ExactSizeIterator<Item = Option<u32>>) -> StatIndex {
+        let len = stats.len();
+        let mut by_value: BTreeMap<u32, BitSet> = BTreeMap::new();
+        let mut unknown = BitSet::new(len);
+        for (i, stat) in stats.enumerate() {
+            match stat {
+                Some(value) => by_value
+                    .entry(value)
+                    .or_insert_with(|| BitSet::new(len))
+                    .set(i),
+                None => unknown.set(i),
+            }
+        }
+
+        let (values, exact): (Vec<u32>, Vec<BitSet>) = by_value.into_iter().unzip();
+        let mut at_most: Vec<BitSet> = Vec::with_capacity(exact.len());
+        for set in &exact {
+            let union = match at_most.last() {
+                Some(previous) => previous.or(set),
+                None => set.clone(),
+            };
+            at_most.push(union);
+        }
+
+        let zero_or_unknown = match values.binary_search(&0) {
+            Ok(position) => exact[position].or(&unknown),
+            Err(_) => unknown.clone(),
+        };
+
+        StatIndex {
+            len,
+            values,
+            exact,
+            at_most,
+            unknown,
+            zero_or_unknown,
+        }
+    }
+
+    /// Monsters with exactly this stat, `None` looks up the unknown stats.
+    /// Returns `None` if no monster has the stat.
+    pub fn get(&self, value: Option<u32>) -> Option<&BitSet> {
+        match value {
+            Some(value) => self
+                .values
+                .binary_search(&value)
+                .ok()
+                .map(|position| &self.exact[position]),
+            None if self.unknown.count_ones() > 0 => Some(&self.unknown),
+            None => None,
+        }
+    }
+
+    /// Monsters whose stat is the same as `value` under the unknown stat policy.
+    /// Returns `None` if no monster shares the stat.
+    pub fn connecting(&self, value: Option<u32>, unknown: UnknownStats) -> Option<&BitSet> {
+        match (unknown, value) {
+            (UnknownStats::AsZero, None | Some(0)) => {
+                Some(&self.zero_or_unknown).filter(|set| set.count_ones() > 0)
+            }
+            (UnknownStats::NeverMatch, None) => None,
+            _ => self.get(value),
+        }
+    }
+
+    /// Monsters with a known stat in `min..=max`.
+    pub fn range(&self, min: u32, max: u32) -> BitSet {
+        let start = self.values.partition_point(|&v| v < min);
+        let end = self.values.partition_point(|&v| v <= max);
+        if start >= end {
+            return BitSet::new(self.len);
+        }
+        match start {
+            0 => self.at_most[end - 1].clone(),
+            _ => self.at_most[end - 1].difference(&self.at_most[start - 1]),
+        }
+    }
+
+    /// Monsters with an unknown ("?") stat.
+    pub fn unknown(&self) -> &BitSet {
+        &self.unknown
+    }
+
+    /// Number of buckets, every distinct known value plus one for unknown stats if there are any.
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.values.len() + usize::from(self.unknown.count_ones() > 0)
+    }
+}
+
+/// A simple inverted index structure providing multiple lookups for monsters,
+/// only compiled for tests as the reference the bitset index is checked against.
+#[cfg(test)]
 pub struct MonsterIndex<'a> {
     pub by_id: HashMap<u32, &'a Monster>,
     pub by_attribute: HashMap<Attribute, HashSet<&'a Monster>>,
@@ -67,10 +186,21 @@ pub struct MonsterIndex<'a> {
     pub by_type: HashMap<Type, HashSet<&'a Monster>>,
     pub by_atk: HashMap<Option<u32>, HashSet<&'a Monster>>,
     pub by_def: HashMap<Option<u32>, HashSet<&'a Monster>>,
+    pub unknown_stats: UnknownStats,
 }
 
+#[cfg(test)]
 impl<'a> MonsterIndex<'a> {
-    pub fn new(monsters: &'a Vec<Monster>) -> MonsterIndex<'a> {
+    pub fn new(monsters: &'a [Monster]) -> MonsterIndex<'a> {
+        MonsterIndex::with_unknown_stats(monsters, UnknownStats::default())
+    }
+
+    /// ATK and DEF are keyed by the value they are compared with, "?" is grouped with 0
+    /// if unknown stats count as 0.
+    pub fn with_unknown_stats(
+        monsters: &'a [Monster],
+        unknown_stats: UnknownStats,
+    ) -> MonsterIndex<'a> {
         let mut by_id: HashMap<u32, &Monster> = HashMap::new();
         let mut by_attribute: HashMap<Attribute, HashSet<&Monster>> = HashMap::new();
         let mut by_level: HashMap<u32, HashSet<&Monster>> = HashMap::new();
@@ -81,19 +211,19 @@ impl<'a> MonsterIndex<'a> {
         for m in monsters {
             by_id.entry(m.id()).insert_entry(m);
             by_attribute
-                .entry(m.attribute())
-                .or_insert_with(HashSet::new)
+                .entry(m.attribute().clone())
+                .or_default()
                 .insert(m);
-            by_level
-                .entry(m.level())
-                .or_insert_with(HashSet::new)
+            by_level.entry(m.level()).or_default().insert(m);
+            by_type.entry(m.r#type().clone()).or_default().insert(m);
+            by_atk
+                .entry(unknown_stats.value(m.atk()))
+                .or_default()
                 .insert(m);
-            by_type
-                .entry(m.r#type())
-                .or_insert_with(HashSet::new)
+            by_def
+                .entry(unknown_stats.value(m.def()))
+                .or_default()
                 .insert(m);
-            by_atk.entry(m.atk()).or_insert_with(HashSet::new).insert(m);
-            by_def.entry(m.def()).or_insert_with(HashSet::new).insert(m);
         }
 
         MonsterIndex {
@@ -103,6 +233,7 @@ impl<'a> MonsterIndex<'a> {
             by_type,
             by_atk,
             by_def,
+            unknown_stats,
         }
     }
 }
@@ -110,11 +241,11 @@ impl<'a> MonsterIndex<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::util::parse_csv_file;
+    use crate::util::{load_csv, parse_csv_file};
 
     #[test]
     fn test_example() {
-        let monsters = parse_csv_file("testing_data.csv");
+        let monsters = parse_csv_file("testing_data.csv").unwrap();
         let index = BitSetIndex::new(&monsters);
         assert_eq!(
             index
@@ -124,21 +255,15 @@ mod tests {
                 .count_ones(),
             4
         );
-        assert_eq!(index.by_level.get(&4).unwrap().count_ones(), 6);
+        assert_eq!(index.by_level.get(Some(4)).unwrap().count_ones(), 6);
         assert_eq!(index.by_type.get(&Type::Fish).unwrap().count_ones(), 4);
-        assert_eq!(
-            index.by_atk.get(&Option::<u32>::None).unwrap().count_ones(),
-            2
-        );
-        assert_eq!(
-            index.by_def.get(&Option::<u32>::None).unwrap().count_ones(),
-            2
-        );
+        assert_eq!(index.by_atk.get(None).unwrap().count_ones(), 2);
+        assert_eq!(index.by_def.get(None).unwrap().count_ones(), 2);
     }
 
     #[test]
     fn test_fixed() {
-        let monsters = parse_csv_file("testing_data.csv");
+        let monsters = parse_csv_file("testing_data.csv").unwrap();
         let index = BitSetIndex::new(&monsters);
         assert_eq!(index.by_attribute.len(), 7);
         assert_eq!(index.by_level.len(), 9);
@@ -147,7 +272,7 @@ mod tests {
 
     #[test]
     fn test_min_grouping() {
-        let monsters = parse_csv_file("testing_data.csv");
+        let monsters = parse_csv_file("testing_data.csv").unwrap();
         let index = BitSetIndex::new(&monsters);
         assert_ne!(index.by_attribute.len(), monsters.len());
         assert_ne!(index.by_level.len(), monsters.len());
@@ -155,4 +280,53 @@ mod tests {
         assert_ne!(index.by_atk.len(), monsters.len());
         assert_ne!(index.by_def.len(), monsters.len());
     }
+
+    #[test]
+    fn test_frames() {
+        let monsters = load_csv(
+            "id,name,attribute,level,type,atk,def,frame,abilities\n\
+            89631139,Blue-Eyes White Dragon,LIGHT,8,Dragon,3000,2500,normal,\n\
+            14558127,Ash Blossom & Joyous Spring,FIRE,3,Zombie,0,1800,effect,Tuner\n\
+            32134638,Dinomist Rex,WATER,6,Dinosaur,2400,1200,effect_pendulum,\n\
+            8240199,Ghost Ogre & Snow Rabbit,LIGHT,3,Psychic,0,1800,effect,Tuner\n",
+            false,
+        )
+        .unwrap()
+        .monsters;
+        let index = BitSetIndex::new(&monsters);
+        assert_eq!(index.by_frame[&Frame::Effect].ones(), vec![1, 3]);
+        assert_eq!(index.by_frame[&Frame::EffectPendulum].ones(), vec![2]);
+        assert_eq!(index.by_ability[&Ability::Tuner].ones(), vec![1, 3]);
+        assert!(!index.by_ability.contains_key(&Ability::Flip));
+
+        // older card databases have no frames
+        let index = BitSetIndex::new(&parse_csv_file("testing_data.csv").unwrap());
+        assert!(index.by_frame.is_empty());
+    }
+
+    #[test]
+    fn test_stat_ranges() {
+        let monsters = parse_csv_file("testing_data.csv").unwrap();
+        let index = BitSetIndex::new(&monsters);
+        for (min, max) in [
+            (0, u32::MAX),
+            (1000, 2000),
+            (1500, 1500),
+            (1501, 1599),
+            (3000, 0),
+        ] {
+            let expected = monsters
+                .iter()
+                .filter(|m| m.atk().is_some_and(|atk| min <= atk && atk <= max))
+                .count() as u32;
+            assert_eq!(index.by_atk.range(min, max).count_ones(), expected);
+        }
+        assert_eq!(
+            index.by_level.range(3, 4).count_ones(),
+            index.by_level.get(Some(3)).unwrap().count_ones() + 6
+        );
+        assert_eq!(index.by_level.unknown().count_ones(), 0);
+        assert!(index.by_level.get(None).is_none());
+        assert!(index.by_atk.get(Some(1)).is_none());
+    }
 }
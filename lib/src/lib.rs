@@ -1,6 +1,16 @@
+pub mod alias;
 mod bitset;
 mod bridge;
+pub mod deck;
+pub mod diagnostics;
+pub mod filter;
 mod index;
 pub mod monster;
+pub mod names;
+pub mod optimizer;
+pub mod probability;
+pub mod rule;
 pub mod searcher;
+pub mod simulation;
+pub mod state;
 pub mod util;
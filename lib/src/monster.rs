@@ -1,6 +1,5 @@
 use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::num::ParseIntError;
 use std::str::FromStr;
 use wasm_bindgen::prelude::*;
 
@@ -14,6 +13,169 @@ pub struct Monster {
     r#type: Type,
     atk: Option<u32>,
     def: Option<u32>,
+    details: MonsterDetails,
+}
+
+/// Optional information of the card database, older files leave it empty.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct MonsterDetails {
+    pub frame: Option<Frame>,
+    pub abilities: Vec<Ability>,
+    pub archetype: Option<String>,
+    pub scale: Option<u32>, // Pendulum Scale
+    pub ban_status: BanStatus,
+    pub release_date: Option<String>, // YYYY-MM-DD
+}
+
+/// The card frame of a Main Deck monster.
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Frame {
+    Normal,
+    Effect,
+    Ritual,
+    NormalPendulum,
+    EffectPendulum,
+    RitualPendulum,
+}
+
+impl Frame {
+    pub const ALL: [Frame; 6] = [
+        Frame::Normal,
+        Frame::Effect,
+        Frame::Ritual,
+        Frame::NormalPendulum,
+        Frame::EffectPendulum,
+        Frame::RitualPendulum,
+    ];
+
+    pub fn is_pendulum(self) -> bool {
+        matches!(
+            self,
+            Frame::NormalPendulum | Frame::EffectPendulum | Frame::RitualPendulum
+        )
+    }
+
+    /// The frame without the Pendulum half, e.g. Normal for a Normal Pendulum monster.
+    pub fn base(self) -> Frame {
+        match self {
+            Frame::NormalPendulum => Frame::Normal,
+            Frame::EffectPendulum => Frame::Effect,
+            Frame::RitualPendulum => Frame::Ritual,
+            frame => frame,
+        }
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Frame::Normal => "normal",
+            Frame::Effect => "effect",
+            Frame::Ritual => "ritual",
+            Frame::NormalPendulum => "normal_pendulum",
+            Frame::EffectPendulum => "effect_pendulum",
+            Frame::RitualPendulum => "ritual_pendulum",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for Frame {
+    type Err = ();
+
+    /// The `frameType` names of the card database.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Frame::ALL
+            .into_iter()
+            .find(|frame| frame.to_string() == string)
+            .ok_or(())
+    }
+}
+
+/// Monster abilities printed next to the type, e.g. "Spellcaster/Tuner".
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Ability {
+    Tuner,
+    Flip,
+    Gemini,
+    Spirit,
+    Toon,
+    Union,
+}
+
+impl Ability {
+    pub const ALL: [Ability; 6] = [
+        Ability::Tuner,
+        Ability::Flip,
+        Ability::Gemini,
+        Ability::Spirit,
+        Ability::Toon,
+        Ability::Union,
+    ];
+}
+
+impl fmt::Display for Ability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Ability::Tuner => "Tuner",
+            Ability::Flip => "Flip",
+            Ability::Gemini => "Gemini",
+            Ability::Spirit => "Spirit",
+            Ability::Toon => "Toon",
+            Ability::Union => "Union",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for Ability {
+    type Err = ();
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Ability::ALL
+            .into_iter()
+            .find(|ability| ability.to_string() == string)
+            .ok_or(())
+    }
+}
+
+/// The status of a card on the banlist.
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum BanStatus {
+    Forbidden,
+    Limited,
+    SemiLimited,
+    #[default]
+    Unlimited,
+}
+
+impl BanStatus {
+    /// The number of copies allowed in a deck.
+    pub fn copies(self) -> u32 {
+        match self {
+            BanStatus::Forbidden => 0,
+            BanStatus::Limited => 1,
+            BanStatus::SemiLimited => 2,
+            BanStatus::Unlimited => 3,
+        }
+    }
+}
+
+impl FromStr for BanStatus {
+    type Err = ();
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Ok(match string {
+            "Forbidden" | "Banned" => BanStatus::Forbidden,
+            "Limited" => BanStatus::Limited,
+            "Semi-Limited" => BanStatus::SemiLimited,
+            "" | "Unlimited" => BanStatus::Unlimited,
+            _ => return Err(()),
+        })
+    }
 }
 
 impl Hash for Monster {
@@ -40,19 +202,64 @@ impl Monster {
             r#type,
             atk,
             def,
+            details: MonsterDetails::default(),
         }
     }
 
+    pub fn with_details(mut self, details: MonsterDetails) -> Monster {
+        self.details = details;
+        self
+    }
+
+    pub fn details(&self) -> &MonsterDetails {
+        &self.details
+    }
+
+    pub fn abilities(&self) -> &[Ability] {
+        &self.details.abilities
+    }
+
+    pub fn has_ability(&self, ability: Ability) -> bool {
+        self.details.abilities.contains(&ability)
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
 
-    pub fn attribute(&self) -> Attribute {
-        self.attribute
+    pub fn attribute(&self) -> &Attribute {
+        &self.attribute
     }
 
-    pub fn r#type(&self) -> Type {
-        self.r#type
+    pub fn r#type(&self) -> &Type {
+        &self.r#type
+    }
+
+    /// Returns every property this monster shares with `other`,
+    /// in the order attribute, level, type, atk, def.
+    /// Unknown ATK and DEF are compared according to `unknown`.
+    pub fn shared_properties(
+        &self,
+        other: &Monster,
+        unknown: UnknownStats,
+    ) -> Vec<ConnectingProperty> {
+        let mut shared = vec![];
+        if self.attribute == other.attribute {
+            shared.push(ConnectingProperty::Attribute(self.attribute.clone()));
+        }
+        if self.level == other.level {
+            shared.push(ConnectingProperty::Level(self.level));
+        }
+        if self.r#type == other.r#type {
+            shared.push(ConnectingProperty::Type(self.r#type.clone()));
+        }
+        if unknown.same(self.atk, other.atk) {
+            shared.push(ConnectingProperty::Atk(unknown.value(self.atk)));
+        }
+        if unknown.same(self.def, other.def) {
+            shared.push(ConnectingProperty::Def(unknown.value(self.def)));
+        }
+        shared
     }
 }
 
@@ -92,9 +299,139 @@ impl Monster {
     pub fn type_js(&self) -> String {
         self.r#type.to_string()
     }
+
+    /// `None` if the card database has no frames
+    #[wasm_bindgen(getter)]
+    pub fn frame(&self) -> Option<Frame> {
+        self.details.frame
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn abilities_js(&self) -> Vec<Ability> {
+        self.details.abilities.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn archetype(&self) -> Option<String> {
+        self.details.archetype.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn scale(&self) -> Option<u32> {
+        self.details.scale
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn ban_status(&self) -> BanStatus {
+        self.details.ban_status
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn release_date(&self) -> Option<String> {
+        self.details.release_date.clone()
+    }
 }
 
+/// The properties compared by Small World.
+#[wasm_bindgen]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Property {
+    Attribute,
+    Level,
+    Type,
+    Atk,
+    Def,
+}
+
+impl Property {
+    pub const ALL: [Property; 5] = [
+        Property::Attribute,
+        Property::Level,
+        Property::Type,
+        Property::Atk,
+        Property::Def,
+    ];
+}
+
+/// How unknown ("?") ATK and DEF values are compared.
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum UnknownStats {
+    /// "?" counts as 0, the ruling for monsters in the Deck
+    AsZero,
+    /// "?" never shares the stat with any monster
+    NeverMatch,
+    /// "?" shares the stat with every other "?"
+    #[default]
+    MatchEachOther,
+}
+
+impl UnknownStats {
+    /// Whether two stats count as the same value.
+    pub fn same(self, a: Option<u32>, b: Option<u32>) -> bool {
+        match self {
+            UnknownStats::AsZero => a.unwrap_or(0) == b.unwrap_or(0),
+            UnknownStats::NeverMatch => a.is_some() && a == b,
+            UnknownStats::MatchEachOther => a == b,
+        }
+    }
+
+    /// The value a stat is compared with.
+    pub fn value(self, stat: Option<u32>) -> Option<u32> {
+        match self {
+            UnknownStats::AsZero => Some(stat.unwrap_or(0)),
+            _ => stat,
+        }
+    }
+}
+
+/// A property shared by two monsters, together with its value.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ConnectingProperty {
+    Attribute(Attribute),
+    Level(u32),
+    Type(Type),
+    Atk(Option<u32>),
+    Def(Option<u32>),
+}
+
+impl ConnectingProperty {
+    pub fn property(&self) -> Property {
+        match self {
+            ConnectingProperty::Attribute(_) => Property::Attribute,
+            ConnectingProperty::Level(_) => Property::Level,
+            ConnectingProperty::Type(_) => Property::Type,
+            ConnectingProperty::Atk(_) => Property::Atk,
+            ConnectingProperty::Def(_) => Property::Def,
+        }
+    }
+
+    /// The shared value as plain text, unknown stats are written as "?".
+    pub fn value(&self) -> String {
+        match self {
+            ConnectingProperty::Attribute(attribute) => attribute.to_string(),
+            ConnectingProperty::Level(level) => level.to_string(),
+            ConnectingProperty::Type(r#type) => r#type.to_string(),
+            ConnectingProperty::Atk(atk) => atk.map_or("?".into(), |v| v.to_string()),
+            ConnectingProperty::Def(def) => def.map_or("?".into(), |v| v.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ConnectingProperty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self.property() {
+            Property::Attribute => "Attribute",
+            Property::Level => "Level",
+            Property::Type => "Type",
+            Property::Atk => "ATK",
+            Property::Def => "DEF",
+        };
+        write!(f, "{}: {}", label, self.value())
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum Attribute {
     DARK,
     DIVINE,
@@ -103,6 +440,21 @@ pub enum Attribute {
     LIGHT,
     WATER,
     WIND,
+    /// an attribute this version does not know yet, as written in the card database
+    Unknown(String),
+}
+
+impl Attribute {
+    /// Parses the attribute, values this version does not know become `Unknown`.
+    pub fn parse_or_unknown(string: &str) -> Attribute {
+        string
+            .parse()
+            .unwrap_or_else(|_| Attribute::Unknown(string.to_string()))
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Attribute::Unknown(_))
+    }
 }
 
 impl fmt::Display for Attribute {
@@ -115,6 +467,7 @@ impl fmt::Display for Attribute {
             Attribute::LIGHT => "LIGHT",
             Attribute::WATER => "WATER",
             Attribute::WIND => "WIND",
+            Attribute::Unknown(s) => s,
         };
         write!(f, "{}", s)
     }
@@ -137,7 +490,7 @@ impl FromStr for Attribute {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum Type {
     Aqua,
     Beast,
@@ -164,6 +517,21 @@ pub enum Type {
     WingedBeast,
     Wyrm,
     Zombie,
+    /// a type this version does not know yet, as written in the card database
+    Unknown(String),
+}
+
+impl Type {
+    /// Parses the type, values this version does not know become `Unknown`.
+    pub fn parse_or_unknown(string: &str) -> Type {
+        string
+            .parse()
+            .unwrap_or_else(|_| Type::Unknown(string.to_string()))
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown(_))
+    }
 }
 
 impl fmt::Display for Type {
@@ -194,6 +562,7 @@ impl fmt::Display for Type {
             Type::WingedBeast => "Winged Beast",
             Type::Wyrm => "Wyrm",
             Type::Zombie => "Zombie",
+            Type::Unknown(s) => s,
         };
         write!(f, "{}", s)
     }
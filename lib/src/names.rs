@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+/// Lowercases the name and drops punctuation, hyphens and underscores become spaces.
+///
+/// ```text
+/// "30,000-Year White Turtle" -> "30000 year white turtle"
+/// "Nibiru, the Primal Being" -> "nibiru the primal being"
+/// ```
+pub fn normalize_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_alphanumeric() {
+            result.extend(c.to_lowercase());
+        } else if (c.is_whitespace() || c == '-' || c == '_' || c == '\u{2010}' || c == '\u{2013}')
+            && !result.is_empty()
+            && !result.ends_with(' ')
+        {
+            result.push(' ');
+        }
+    }
+    if result.ends_with(' ') {
+        result.pop();
+    }
+    result
+}
+
+type Trigram = [char; 3];
+
+/// The trigrams of a normalized name, padded with a space on both ends.
+fn trigrams(name: &str) -> Vec<Trigram> {
+    let chars = std::iter::once(' ')
+        .chain(name.chars())
+        .chain(std::iter::once(' '))
+        .collect::<Vec<_>>();
+    let mut grams = chars
+        .windows(3)
+        .map(|w| [w[0], w[1], w[2]])
+        .collect::<Vec<_>>();
+    grams.sort();
+    grams.dedup();
+    grams
+}
+
+/// A trigram index over monster names for typo-tolerant lookups.
+pub(crate) struct NameIndex {
+    names: Vec<String>,    // normalized names, in monster order
+    gram_counts: Vec<u32>, // number of distinct trigrams of every name
+    postings: HashMap<Trigram, Vec<u32>>,
+}
+
+impl NameIndex {
+    pub(crate) fn new<'a>(names: impl Iterator<Item = &'a str>) -> NameIndex {
+        let mut index = NameIndex {
+            names: vec![],
+            gram_counts: vec![],
+            postings: HashMap::new(),
+        };
+        for (i, name) in names.enumerate() {
+            let name = normalize_name(name);
+            let grams = trigrams(&name);
+            index.gram_counts.push(grams.len() as u32);
+            for gram in grams {
+                index.postings.entry(gram).or_default().push(i as u32);
+            }
+            index.names.push(name);
+        }
+        index
+    }
+
+    /// The positions of the best matching names, best first.
+    ///
+    /// Names are ranked by the Dice coefficient of their trigrams with the query, so small typos
+    /// still score high. Exact matches come first, then names starting with or containing the query.
+    pub(crate) fn search(&self, query: &str, limit: usize) -> Vec<usize> {
+        self.scored(query)
+            .into_iter()
+            .take(limit)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// The best matching name if its trigram similarity is at least `min_similarity`,
+    /// e.g. to resolve the card names of a plain-text decklist.
+    pub(crate) fn best(&self, query: &str, min_similarity: f64) -> Option<usize> {
+        let query = normalize_name(query);
+        let query_grams = trigrams(&query);
+        self.scored(&query)
+            .into_iter()
+            .next()
+            .filter(|&(i, _)| self.similarity(i, &query_grams) >= min_similarity)
+            .map(|(i, _)| i)
+    }
+
+    fn similarity(&self, i: usize, query_grams: &[Trigram]) -> f64 {
+        let shared = query_grams
+            .iter()
+            .filter(|gram| {
+                self.postings
+                    .get(*gram)
+                    .is_some_and(|p| p.binary_search(&(i as u32)).is_ok())
+            })
+            .count();
+        2.0 * shared as f64 / (query_grams.len() + self.gram_counts[i] as usize) as f64
+    }
+
+    /// Every name sharing a trigram with the query with its score, best first.
+    fn scored(&self, query: &str) -> Vec<(usize, f64)> {
+        let query = normalize_name(query);
+        if query.is_empty() {
+            return vec![];
+        }
+        let query_grams = trigrams(&query);
+
+        let mut shared: HashMap<u32, u32> = HashMap::new();
+        for gram in &query_grams {
+            for &i in self.postings.get(gram).into_iter().flatten() {
+                *shared.entry(i).or_default() += 1;
+            }
+        }
+
+        let mut scored = shared
+            .into_iter()
+            .map(|(i, shared)| {
+                let i = i as usize;
+                let name = &self.names[i];
+                let dice =
+                    2.0 * shared as f64 / (query_grams.len() as u32 + self.gram_counts[i]) as f64;
+                let bonus = if *name == query {
+                    3.0
+                } else if name.starts_with(&query) {
+                    2.0
+                } else if name.contains(&query) {
+                    1.0
+                } else {
+                    0.0
+                };
+                (i, dice + bonus)
+            })
+            .collect::<Vec<_>>();
+        scored.sort_by(|(i, a), (j, b)| {
+            b.total_cmp(a)
+                .then(self.names[*i].len().cmp(&self.names[*j].len()))
+                .then(i.cmp(j))
+        });
+        scored
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::parse_csv_file;
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(
+            normalize_name("30,000-Year White Turtle"),
+            "30000 year white turtle"
+        );
+        assert_eq!(
+            normalize_name("\"Infernoble Arms - Joyeuse\""),
+            "infernoble arms joyeuse"
+        );
+        assert_eq!(normalize_name("Achichi @Ignister"), "achichi ignister");
+        assert_eq!(normalize_name(" Blue–Eyes  "), "blue eyes");
+    }
+
+    #[test]
+    fn test_search() {
+        let monsters = parse_csv_file("testing_data.csv").unwrap();
+        let index = NameIndex::new(monsters.iter().map(|m| m.name()));
+        let name = |i: usize| monsters[i].name().to_string();
+
+        assert_eq!(
+            name(index.search("blue eyes whte dragon", 1)[0]),
+            "Blue-Eyes White Dragon"
+        );
+        assert_eq!(name(index.search("lacoda", 1)[0]), "3-Hump Lacooda");
+        assert_eq!(
+            name(index.search("nibiru primal", 1)[0]),
+            "Nibiru, the Primal Being"
+        );
+        // names starting with the query rank before names only containing it
+        let blue = index.search("blue", 2);
+        assert_eq!(name(blue[0]), "Blue-Eyes White Dragon");
+        assert_eq!(name(blue[1]), "Sage with Eyes of Blue");
+        assert_eq!(index.search("dragon", 3).len(), 3);
+        assert!(index.search("", 3).is_empty());
+
+        assert_eq!(
+            index.best("Ash Blossom and Joyous Spring", 0.5).map(name),
+            Some("Ash Blossom & Joyous Spring".to_string())
+        );
+        assert_eq!(index.best("Pot of Greed", 0.5), None);
+    }
+}
@@ -0,0 +1,282 @@
+use crate::deck::{Deck, MAX_COPIES};
+use crate::diagnostics::Diagnostics;
+use crate::probability::{DeckGraph, opening_odds};
+use crate::simulation::Rng;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OptimizerStrategy {
+    /// add the best single copy until the budget is used or nothing improves
+    Greedy,
+    /// greedy, followed by random swaps of added copies that are kept if they improve
+    LocalSearch,
+}
+
+/// Settings of the deck optimizer.
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone)]
+pub struct OptimizerConfig {
+    /// maximum number of cards added to the core
+    pub budget: u32,
+    pub hand_size: u32,
+    pub strategy: OptimizerStrategy,
+    /// number of swaps tried by the local search
+    pub iterations: u32,
+    pub seed: u64,
+}
+
+#[wasm_bindgen]
+impl OptimizerConfig {
+    #[wasm_bindgen(constructor)]
+    pub fn new(budget: u32, hand_size: u32) -> OptimizerConfig {
+        OptimizerConfig {
+            budget,
+            hand_size,
+            strategy: OptimizerStrategy::Greedy,
+            iterations: 200,
+            seed: 0,
+        }
+    }
+}
+
+/// The best deck found by the optimizer.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct OptimizedDeck {
+    deck: Deck,
+    coverage: f64,
+    evaluations: u32,
+    diagnostics: Diagnostics,
+}
+
+impl OptimizedDeck {
+    pub(crate) fn new(
+        deck: Deck,
+        coverage: f64,
+        evaluations: u32,
+        diagnostics: Diagnostics,
+    ) -> Self {
+        OptimizedDeck {
+            deck,
+            coverage,
+            evaluations,
+            diagnostics,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl OptimizedDeck {
+    /// the core with the selected candidates added to the main deck
+    #[wasm_bindgen(getter)]
+    pub fn deck(&self) -> Deck {
+        self.deck.clone()
+    }
+
+    /// mean probability to reach each target
+    #[wasm_bindgen(getter)]
+    pub fn coverage(&self) -> f64 {
+        self.coverage
+    }
+
+    /// number of decks whose odds were computed
+    #[wasm_bindgen(getter)]
+    pub fn evaluations(&self) -> u32 {
+        self.evaluations
+    }
+
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
+    }
+}
+
+/// Expected share of the targets reachable with Small World, the mean of the per target odds.
+pub(crate) fn coverage(graph: &DeckGraph, hand_size: u32, targets: &[u32]) -> f64 {
+    if targets.is_empty() {
+        return 0.0;
+    }
+    let odds = opening_odds(graph, hand_size, targets);
+    odds.per_target().iter().sum::<f64>() / targets.len() as f64
+}
+
+/// Searches the copies of the candidate monsters that maximize the coverage of the targets.
+///
+/// The graph holds the core with its copies and the candidates with zero copies, the copies
+/// of the best deck found are left in the graph. Returns the coverage and the number of evaluated decks.
+pub(crate) fn optimize(
+    graph: &mut DeckGraph,
+    candidates: &[usize],
+    targets: &[u32],
+    config: &OptimizerConfig,
+) -> (f64, u32) {
+    let core = graph.copies().to_vec();
+    let mut evaluations = 1;
+    let mut best = coverage(graph, config.hand_size, targets);
+    let mut added = 0;
+
+    while added < config.budget {
+        let mut best_move = None;
+        for &candidate in candidates {
+            let copies = graph.copies()[candidate];
+            if copies >= MAX_COPIES {
+                continue;
+            }
+            graph.set_copies(candidate, copies + 1);
+            let score = coverage(graph, config.hand_size, targets);
+            evaluations += 1;
+            graph.set_copies(candidate, copies);
+            if score > best_move.map_or(best, |(_, s)| s) {
+                best_move = Some((candidate, score));
+            }
+        }
+
+        let Some((candidate, score)) = best_move else {
+            break;
+        };
+        graph.set_copies(candidate, graph.copies()[candidate] + 1);
+        best = score;
+        added += 1;
+    }
+
+    if config.strategy == OptimizerStrategy::LocalSearch && !candidates.is_empty() {
+        let mut rng = Rng::new(config.seed);
+        for _ in 0..config.iterations {
+            let removable = candidates
+                .iter()
+                .copied()
+                .filter(|&c| graph.copies()[c] > core[c])
+                .collect::<Vec<_>>();
+            // a pure add needs budget left, otherwise an added copy is swapped
+            let remove = if added < config.budget && (removable.is_empty() || rng.below(2) == 0) {
+                None
+            } else if removable.is_empty() {
+                continue;
+            } else {
+                Some(removable[rng.below(removable.len())])
+            };
+            let add = candidates[rng.below(candidates.len())];
+            if Some(add) == remove || graph.copies()[add] >= MAX_COPIES {
+                continue;
+            }
+
+            if let Some(remove) = remove {
+                graph.set_copies(remove, graph.copies()[remove] - 1);
+            }
+            graph.set_copies(add, graph.copies()[add] + 1);
+            let score = coverage(graph, config.hand_size, targets);
+            evaluations += 1;
+
+            if score > best {
+                best = score;
+                if remove.is_none() {
+                    added += 1;
+                }
+            } else {
+                graph.set_copies(add, graph.copies()[add] - 1);
+                if let Some(remove) = remove {
+                    graph.set_copies(remove, graph.copies()[remove] + 1);
+                }
+            }
+        }
+    }
+
+    (best, evaluations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BRIDGE: usize = 1;
+    const ISOLATED: usize = 3;
+
+    /// 1, 2 and 3 are connected with each other and 4 is isolated, 2 and 4 are the candidates
+    fn example_graph() -> DeckGraph {
+        let monsters = vec![(1, 3), (2, 0), (3, 1), (4, 0)];
+        let edges = [(0, 1), (1, 2), (0, 2)];
+        DeckGraph::new(monsters, 1, 30, |i, j| {
+            edges.contains(&(i, j)) || edges.contains(&(j, i))
+        })
+    }
+
+    fn config(budget: u32, strategy: OptimizerStrategy) -> OptimizerConfig {
+        OptimizerConfig {
+            strategy,
+            ..OptimizerConfig::new(budget, 5)
+        }
+    }
+
+    #[test]
+    fn test_coverage() {
+        let mut graph = example_graph();
+        assert_eq!(coverage(&graph, 5, &[]), 0.0);
+        // no bridge in the deck
+        assert_eq!(coverage(&graph, 5, &[3]), 0.0);
+
+        graph.set_copies(BRIDGE, 1);
+        let odds = opening_odds(&graph, 5, &[3, 1]);
+        let mean = (odds.per_target()[0] + odds.per_target()[1]) / 2.0;
+        assert!(coverage(&graph, 5, &[3]) > 0.0);
+        assert_eq!(coverage(&graph, 5, &[3, 1]), mean);
+    }
+
+    #[test]
+    fn test_budget() {
+        for strategy in [OptimizerStrategy::Greedy, OptimizerStrategy::LocalSearch] {
+            for budget in 0..3 {
+                let mut graph = example_graph();
+                let core = graph.copies().to_vec();
+                let (coverage, _) = optimize(
+                    &mut graph,
+                    &[BRIDGE, ISOLATED],
+                    &[3],
+                    &config(budget, strategy),
+                );
+                let added = graph.copies().iter().sum::<u32>() - core.iter().sum::<u32>();
+                assert!(added <= budget);
+                assert_eq!(coverage > 0.0, budget > 0);
+            }
+        }
+
+        let mut graph = example_graph();
+        let (coverage, evaluations) = optimize(
+            &mut graph,
+            &[BRIDGE],
+            &[3],
+            &config(0, OptimizerStrategy::Greedy),
+        );
+        assert_eq!((coverage, evaluations), (0.0, 1));
+        assert_eq!(graph.copies(), example_graph().copies());
+    }
+
+    #[test]
+    fn test_max_copies() {
+        // every copy of the bridge helps, a fourth one would too
+        for strategy in [OptimizerStrategy::Greedy, OptimizerStrategy::LocalSearch] {
+            let mut graph = example_graph();
+            optimize(&mut graph, &[BRIDGE, ISOLATED], &[3], &config(10, strategy));
+            assert_eq!(graph.copies()[BRIDGE], MAX_COPIES);
+            // a copy of the isolated monster only makes drawing the bridge less likely
+            assert_eq!(graph.copies()[ISOLATED], 0);
+        }
+    }
+
+    #[test]
+    fn test_seed() {
+        let run = |seed: u64| {
+            let mut graph = example_graph();
+            let config = OptimizerConfig {
+                seed,
+                iterations: 50,
+                ..config(2, OptimizerStrategy::LocalSearch)
+            };
+            let (coverage, evaluations) = optimize(&mut graph, &[BRIDGE, ISOLATED], &[3], &config);
+            (graph.copies().to_vec(), coverage, evaluations)
+        };
+        assert_eq!(run(7), run(7));
+        assert_eq!(run(0), run(0));
+    }
+}
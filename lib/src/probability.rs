@@ -0,0 +1,331 @@
+use crate::diagnostics::Diagnostics;
+use wasm_bindgen::prelude::*;
+
+pub const SMALL_WORLD_ID: u32 = 89558743;
+pub const HAND_SIZE_FIRST: u32 = 5;
+pub const HAND_SIZE_SECOND: u32 = 6;
+
+/// The Small World relevant content of a main deck.
+///
+/// Monsters are numbered locally and sets of them are stored as `u128` masks,
+/// a main deck has at most 60 cards so this covers every legal deck. Monsters
+/// beyond the first 128 distinct ones are counted as other cards.
+pub(crate) struct DeckGraph {
+    ids: Vec<u32>,
+    copies: Vec<u32>,
+    neighbors: Vec<u128>, // neighbors[i]: monsters connected to monster i
+    small_world: u32,     // copies of Small World
+    other: u32,           // every other card
+}
+
+impl DeckGraph {
+    pub(crate) const MAX_MONSTERS: usize = 128;
+
+    /// `connected(i, j)` tells whether the i-th and j-th monster are connected.
+    pub(crate) fn new(
+        monsters: Vec<(u32, u32)>,
+        small_world: u32,
+        other: u32,
+        connected: impl Fn(usize, usize) -> bool,
+    ) -> DeckGraph {
+        let (ids, copies): (Vec<u32>, Vec<u32>) = monsters.into_iter().unzip();
+        let neighbors = (0..ids.len())
+            .map(|i| {
+                (0..ids.len())
+                    .filter(|&j| connected(i, j))
+                    .fold(0, |mask, j| mask | (1 << j))
+            })
+            .collect();
+        DeckGraph {
+            ids,
+            copies,
+            neighbors,
+            small_world,
+            other,
+        }
+    }
+
+    pub(crate) fn ids(&self) -> &[u32] {
+        &self.ids
+    }
+
+    /// Copies of every monster, in local order.
+    pub(crate) fn copies(&self) -> &[u32] {
+        &self.copies
+    }
+
+    pub(crate) fn set_copies(&mut self, position: usize, copies: u32) {
+        self.copies[position] = copies;
+    }
+
+    pub(crate) fn small_world(&self) -> u32 {
+        self.small_world
+    }
+
+    pub(crate) fn other(&self) -> u32 {
+        self.other
+    }
+
+    pub(crate) fn size(&self) -> u32 {
+        self.copies.iter().sum::<u32>() + self.small_world + self.other
+    }
+
+    pub(crate) fn position(&self, id: u32) -> Option<usize> {
+        self.ids.iter().position(|&i| i == id)
+    }
+
+    /// Masks of the monsters in the hand and of the monsters with a copy left in the deck,
+    /// given how many copies of every monster were drawn.
+    pub(crate) fn hand_and_deck(&self, drawn: &[u32]) -> (u128, u128) {
+        let (mut hand, mut deck) = (0, 0);
+        for (i, (&d, &c)) in drawn.iter().zip(&self.copies).enumerate() {
+            if d > 0 {
+                hand |= 1 << i;
+            }
+            if d < c {
+                deck |= 1 << i;
+            }
+        }
+        (hand, deck)
+    }
+
+    /// Whether the target can be added to the hand with Small World, given the monsters
+    /// in the hand and the monsters that still have a copy in the deck.
+    pub(crate) fn reaches(&self, target: usize, hand: u128, deck: u128) -> bool {
+        if deck & (1 << target) == 0 {
+            return false;
+        }
+        let mut bridges = self.neighbors[target] & deck;
+        while bridges != 0 {
+            let bridge = bridges.trailing_zeros() as usize;
+            if self.neighbors[bridge] & hand != 0 {
+                return true;
+            }
+            bridges &= bridges - 1;
+        }
+        false
+    }
+}
+
+/// Chance to open Small World together with a way to reach the targets.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct OpeningOdds {
+    small_world: f64,
+    targets: Vec<u32>,
+    per_target: Vec<f64>,
+    any_target: f64,
+    diagnostics: Diagnostics,
+}
+
+impl OpeningOdds {
+    pub(crate) fn with_diagnostics(self, diagnostics: Diagnostics) -> Self {
+        OpeningOdds {
+            diagnostics,
+            ..self
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl OpeningOdds {
+    /// probability to open at least one Small World
+    #[wasm_bindgen(getter)]
+    pub fn small_world(&self) -> f64 {
+        self.small_world
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn targets(&self) -> Vec<u32> {
+        self.targets.clone()
+    }
+
+    /// probability to reach each target, in the order of `targets`
+    #[wasm_bindgen(getter)]
+    pub fn per_target(&self) -> Vec<f64> {
+        self.per_target.clone()
+    }
+
+    /// probability to reach at least one of the targets
+    #[wasm_bindgen(getter)]
+    pub fn any_target(&self) -> f64 {
+        self.any_target
+    }
+
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
+    }
+}
+
+/// Computes the exact opening odds by enumerating every hand composition,
+/// weighted with the multivariate hypergeometric distribution.
+///
+/// A target counts as reached if the hand holds Small World and a monster that connects
+/// to a bridge with a copy left in the deck, which connects to a target with a copy left in the deck.
+pub(crate) fn opening_odds(graph: &DeckGraph, hand_size: u32, targets: &[u32]) -> OpeningOdds {
+    let target_positions = targets
+        .iter()
+        .map(|&id| graph.position(id))
+        .collect::<Vec<_>>();
+
+    let deck_size = graph.size();
+    let hand_size = hand_size.min(deck_size);
+    let total = binomial(deck_size, hand_size);
+
+    let mut per_target = vec![0.0; targets.len()];
+    let mut any_target = 0.0;
+
+    // with Small World first, hands without it are skipped entirely
+    for small_world in 1..=graph.small_world.min(hand_size) {
+        let weight = binomial(graph.small_world, small_world);
+        let mut drawn = vec![0; graph.copies.len()];
+        enumerate_hands(
+            graph,
+            0,
+            hand_size - small_world,
+            weight,
+            &mut drawn,
+            &mut |drawn, weight| {
+                let (hand, deck) = graph.hand_and_deck(drawn);
+
+                let mut any = false;
+                for (p, target) in per_target.iter_mut().zip(&target_positions) {
+                    if target.is_some_and(|t| graph.reaches(t, hand, deck)) {
+                        *p += weight;
+                        any = true;
+                    }
+                }
+                if any {
+                    any_target += weight;
+                }
+            },
+        );
+    }
+
+    let no_small_world = binomial(deck_size - graph.small_world, hand_size);
+
+    OpeningOdds {
+        small_world: 1.0 - no_small_world / total,
+        targets: targets.to_vec(),
+        per_target: per_target.into_iter().map(|p| p / total).collect(),
+        any_target: any_target / total,
+        diagnostics: Diagnostics::default(),
+    }
+}
+
+/// Calls `visit` for every way to draw `remaining` cards from the monsters starting at `position`
+/// and the other cards, with the number of hands that lead to the drawn monster counts.
+fn enumerate_hands(
+    graph: &DeckGraph,
+    position: usize,
+    remaining: u32,
+    weight: f64,
+    drawn: &mut Vec<u32>,
+    visit: &mut impl FnMut(&[u32], f64),
+) {
+    if position == graph.copies.len() {
+        if remaining <= graph.other {
+            visit(drawn, weight * binomial(graph.other, remaining));
+        }
+        return;
+    }
+
+    let copies = graph.copies[position];
+    for k in 0..=copies.min(remaining) {
+        drawn[position] = k;
+        enumerate_hands(
+            graph,
+            position + 1,
+            remaining - k,
+            weight * binomial(copies, k),
+            drawn,
+            visit,
+        );
+    }
+    drawn[position] = 0;
+}
+
+pub(crate) fn binomial(n: u32, k: u32) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    let k = k.min(n - k);
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -- b -- c, d is isolated
+    fn example_graph() -> DeckGraph {
+        let monsters = vec![(1, 2), (2, 1), (3, 2), (4, 1)];
+        let edges = [(0, 1), (1, 2)];
+        DeckGraph::new(monsters, 2, 6, |i, j| {
+            edges.contains(&(i, j)) || edges.contains(&(j, i))
+        })
+    }
+
+    #[test]
+    fn test_reaches() {
+        let graph = example_graph();
+        // reveal 1, bridge 2, target 3
+        assert!(graph.reaches(2, 0b0001, 0b0110));
+        // the only bridge is not in the deck anymore
+        assert!(!graph.reaches(2, 0b0011, 0b0100));
+        // the target is not in the deck anymore
+        assert!(!graph.reaches(2, 0b0001, 0b0010));
+        assert!(!graph.reaches(3, 0b0111, 0b1111));
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(40, 0), 1.0);
+        assert_eq!(binomial(40, 5), 658008.0);
+        assert_eq!(binomial(3, 4), 0.0);
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        let graph = example_graph();
+        // card list: 1 1 2 3 3 4 SW SW other*6
+        let mut cards = vec![Some(0), Some(0), Some(1), Some(2), Some(2), Some(3)];
+        cards.extend([None; 8]);
+        let is_small_world = |i: usize| i == 6 || i == 7;
+
+        let hand_size = 5;
+        let mut hits = [0u32; 2];
+        let mut hands = 0u32;
+        for mask in 0u32..(1 << cards.len()) {
+            if mask.count_ones() != hand_size {
+                continue;
+            }
+            hands += 1;
+            let mut drawn = [0; 4];
+            let mut small_world = false;
+            for (i, card) in cards.iter().enumerate() {
+                if mask & (1 << i) != 0 {
+                    small_world |= is_small_world(i);
+                    if let Some(m) = card {
+                        drawn[*m] += 1;
+                    }
+                }
+            }
+            let (hand, deck) = graph.hand_and_deck(&drawn);
+            for (hit, target) in hits.iter_mut().zip([0, 2]) {
+                if small_world && graph.reaches(target, hand, deck) {
+                    *hit += 1;
+                }
+            }
+        }
+
+        let odds = opening_odds(&graph, hand_size, &[1, 3]);
+        for (p, hit) in odds.per_target().iter().zip(hits) {
+            assert!((p - hit as f64 / hands as f64).abs() < 1e-12);
+        }
+        assert!(odds.any_target() >= odds.per_target()[0]);
+        assert!((odds.small_world() - (1.0 - binomial(12, 5) / binomial(14, 5))).abs() < 1e-12);
+    }
+}
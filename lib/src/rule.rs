@@ -0,0 +1,97 @@
+use crate::monster::{ConnectingProperty, Monster, Property, UnknownStats};
+use wasm_bindgen::prelude::*;
+
+/// How many of the participating properties two monsters have to share.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MatchCount {
+    Exactly(u32),
+    AtLeast(u32),
+    AtMost(u32),
+}
+
+/// Describes when two monsters are connected.
+///
+/// Small World itself connects monsters that share exactly one of attribute,
+/// level, type, ATK and DEF, which is the default rule.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConnectionRule {
+    properties: Vec<Property>,
+    count: MatchCount,
+}
+
+impl ConnectionRule {
+    pub fn new(properties: &[Property], count: MatchCount) -> Self {
+        ConnectionRule {
+            properties: Property::ALL
+                .into_iter()
+                .filter(|p| properties.contains(p))
+                .collect(),
+            count,
+        }
+    }
+
+    /// The participating properties, in the order attribute, level, type, atk, def.
+    pub fn properties(&self) -> &[Property] {
+        &self.properties
+    }
+
+    pub fn count(&self) -> MatchCount {
+        self.count
+    }
+
+    /// Whether the given number of shared properties satisfies the rule.
+    pub fn accepts(&self, matches: u32) -> bool {
+        match self.count {
+            MatchCount::Exactly(n) => matches == n,
+            MatchCount::AtLeast(n) => matches >= n,
+            MatchCount::AtMost(n) => matches <= n,
+        }
+    }
+
+    /// The participating properties both monsters share.
+    pub fn shared_properties(
+        &self,
+        m1: &Monster,
+        m2: &Monster,
+        unknown: UnknownStats,
+    ) -> Vec<ConnectingProperty> {
+        m1.shared_properties(m2, unknown)
+            .into_iter()
+            .filter(|shared| self.properties.contains(&shared.property()))
+            .collect()
+    }
+
+    pub fn connects(&self, m1: &Monster, m2: &Monster, unknown: UnknownStats) -> bool {
+        self.accepts(self.shared_properties(m1, m2, unknown).len() as u32)
+    }
+}
+
+#[wasm_bindgen]
+impl ConnectionRule {
+    /// Monsters have to share exactly n of the given properties.
+    pub fn exactly(properties: Vec<Property>, n: u32) -> ConnectionRule {
+        ConnectionRule::new(&properties, MatchCount::Exactly(n))
+    }
+
+    /// Monsters have to share at least n of the given properties.
+    pub fn at_least(properties: Vec<Property>, n: u32) -> ConnectionRule {
+        ConnectionRule::new(&properties, MatchCount::AtLeast(n))
+    }
+
+    /// Monsters may share at most n of the given properties.
+    pub fn at_most(properties: Vec<Property>, n: u32) -> ConnectionRule {
+        ConnectionRule::new(&properties, MatchCount::AtMost(n))
+    }
+
+    /// The Small World rule: exactly one of attribute, level, type, ATK and DEF.
+    pub fn small_world() -> ConnectionRule {
+        ConnectionRule::default()
+    }
+}
+
+impl Default for ConnectionRule {
+    fn default() -> Self {
+        ConnectionRule::new(&Property::ALL, MatchCount::Exactly(1))
+    }
+}
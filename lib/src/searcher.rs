@@ -1,17 +1,61 @@
+use crate::alias::AliasTable;
 use crate::bitset::BitSet;
-use crate::bridge::{find_neighborhood_bitset, search_bridge_bitset};
+use crate::bridge::{compute_adjacency, find_neighborhood_bitset};
+use crate::deck::{Deck, Section};
+use crate::diagnostics::{Diagnostics, IgnoreReason, IgnoredId};
+use crate::filter::{CardFilter, FilterError};
 use crate::index::BitSetIndex;
-use crate::monster::Monster;
-use core::panic;
-use std::collections::HashMap;
+use crate::monster::{ConnectingProperty, Monster, Property, UnknownStats};
+use crate::names::NameIndex;
+use crate::optimizer::{OptimizedDeck, OptimizerConfig, optimize};
+use crate::probability::{DeckGraph, OpeningOdds, SMALL_WORLD_ID, opening_odds};
+use crate::rule::ConnectionRule;
+use crate::simulation::{SimulationResult, simulate};
+use crate::util::{CsvDatabase, CsvError, CsvWarning, SCHEMA_VERSION, load_csv};
+use std::borrow::Cow;
+use std::cell::OnceCell;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::vec;
 use wasm_bindgen::prelude::*;
 
+/// minimum trigram similarity for `resolve_name`
+const NAME_SIMILARITY: f64 = 0.5;
+
 #[wasm_bindgen]
 pub struct SmallWorldSearcher {
     monsters: Vec<Monster>,
     id2index: HashMap<u32, usize>,
+    aliases: AliasTable,
+    other_cards: HashSet<u32>, // passcodes of cards that are no main deck monsters
+    load_warnings: Vec<CsvWarning>,
+    schema_version: u32,
     index: BitSetIndex,
+    names: NameIndex,
+    rule: ConnectionRule,
+    adjacency: OnceCell<Vec<BitSet>>, // neighborhood of every monster, built on demand
+}
+
+impl SmallWorldSearcher {
+    /// Load the card database, failing on the first row that can not be parsed.
+    pub fn try_from_csv(data: &str) -> Result<SmallWorldSearcher, CsvError> {
+        load_csv(data, false).map(SmallWorldSearcher::from_database)
+    }
+
+    /// Load the card database, skipping the rows that can not be parsed.
+    /// Fails only if a required column is missing.
+    pub fn try_from_csv_lenient(data: &str) -> Result<SmallWorldSearcher, CsvError> {
+        load_csv(data, true).map(SmallWorldSearcher::from_database)
+    }
+
+    fn from_database(database: CsvDatabase) -> Self {
+        let mut searcher = SmallWorldSearcher::new(database.monsters);
+        searcher.aliases = database.aliases;
+        searcher.other_cards = database.other_cards.into_iter().collect();
+        searcher.load_warnings = database.warnings;
+        searcher.schema_version = database.schema_version;
+        searcher
+    }
 }
 
 #[wasm_bindgen]
@@ -19,19 +63,130 @@ impl SmallWorldSearcher {
     fn new(monsters: Vec<Monster>) -> Self {
         SmallWorldSearcher {
             index: BitSetIndex::new(&monsters),
+            names: NameIndex::new(monsters.iter().map(|m| m.name())),
             id2index: monsters
                 .iter()
                 .enumerate()
                 .map(|(i, m)| (m.id(), i))
                 .collect::<HashMap<u32, usize>>(),
+            aliases: AliasTable::new(),
+            other_cards: HashSet::new(),
+            load_warnings: vec![],
+            schema_version: SCHEMA_VERSION,
             monsters,
+            rule: ConnectionRule::default(),
+            adjacency: OnceCell::new(),
         }
     }
 
     #[wasm_bindgen(constructor)]
-    pub fn from_csv(data: &str) -> Self {
-        let monsters = crate::util::parse_csv(data);
-        SmallWorldSearcher::new(monsters)
+    pub fn from_csv(data: &str) -> Result<SmallWorldSearcher, JsError> {
+        Ok(SmallWorldSearcher::try_from_csv(data)?)
+    }
+
+    /// Like the constructor, but rows that can not be parsed are skipped and
+    /// reported in `load_warnings` instead of failing.
+    pub fn from_csv_lenient(data: &str) -> Result<SmallWorldSearcher, JsError> {
+        Ok(SmallWorldSearcher::try_from_csv_lenient(data)?)
+    }
+
+    /// Attributes and types of the card database this version does not know, and skipped rows.
+    /// Monsters with unknown values are loaded anyway and only match monsters with the same value.
+    pub fn load_warnings(&self) -> Vec<CsvWarning> {
+        self.load_warnings.clone()
+    }
+
+    /// The schema version of the loaded card database, older than `csv_schema_version()`
+    /// if it was cached before the schema changed.
+    #[wasm_bindgen(getter)]
+    pub fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    /// Replace the rule that decides when two monsters are connected, all following queries use it.
+    pub fn set_connection_rule(&mut self, rule: &ConnectionRule) {
+        self.rule = rule.clone();
+        self.adjacency = OnceCell::new();
+    }
+
+    /// Precompute the neighborhood of every monster, all following queries look them up
+    /// instead of recomputing them. Takes a few MB for the full card pool.
+    pub fn precompute_adjacency(&self) {
+        self.adjacency
+            .get_or_init(|| compute_adjacency(&self.monsters, &self.index, &self.rule));
+    }
+
+    pub fn is_adjacency_precomputed(&self) -> bool {
+        self.adjacency.get().is_some()
+    }
+
+    pub fn connection_rule(&self) -> ConnectionRule {
+        self.rule.clone()
+    }
+
+    /// Replace how unknown ("?") ATK and DEF are compared, all following queries use it.
+    pub fn set_unknown_stats(&mut self, unknown: UnknownStats) {
+        self.index.unknown_stats = unknown;
+        self.adjacency = OnceCell::new();
+    }
+
+    pub fn unknown_stats(&self) -> UnknownStats {
+        self.index.unknown_stats
+    }
+
+    /// Replace the alternate artwork passcodes loaded with the card database, all following
+    /// queries accept them in place of the canonical passcodes.
+    pub fn set_aliases(&mut self, aliases: &AliasTable) {
+        self.aliases = aliases.clone();
+    }
+
+    pub fn aliases(&self) -> AliasTable {
+        self.aliases.clone()
+    }
+
+    /// The passcode the database uses for the monster, resolving alternate artworks.
+    /// `None` if the monster is not in the database.
+    pub fn canonical_id(&self, id: u32) -> Option<u32> {
+        self.index_of(id).map(|idx| self.monsters[idx].id())
+    }
+
+    /// Replace the passcodes of Spells, Traps and Extra Deck monsters loaded with the card database,
+    /// so diagnostics can tell them apart from passcodes that are not in the database at all.
+    pub fn set_other_cards(&mut self, ids: &[u32]) {
+        self.other_cards = ids.iter().copied().collect();
+    }
+
+    /// Classify the passcodes that would be ignored or resolved as alias by a query,
+    /// e.g. to explain why a single card lookup returned nothing.
+    pub fn diagnose(&self, ids: &[u32]) -> Diagnostics {
+        self.diagnostics_for(ids)
+    }
+
+    fn diagnostics_for<'a>(&self, ids: impl IntoIterator<Item = &'a u32>) -> Diagnostics {
+        let mut diagnostics = Diagnostics::default();
+        for &id in ids {
+            if self.id2index.contains_key(&id) {
+                continue;
+            }
+            let canonical = self.aliases.canonical(id);
+            let entry = if self.id2index.contains_key(&canonical) {
+                IgnoredId::new(id, IgnoreReason::AliasResolved, Some(canonical))
+            } else if self.other_cards.contains(&canonical) || canonical == SMALL_WORLD_ID {
+                IgnoredId::new(id, IgnoreReason::NotMainDeckMonster, None)
+            } else {
+                IgnoredId::new(id, IgnoreReason::NotInDatabase, None)
+            };
+            diagnostics.push(entry);
+        }
+        diagnostics
+    }
+
+    fn index_of(&self, id: u32) -> Option<usize> {
+        self.id2index.get(&self.aliases.canonical(id)).copied()
+    }
+
+    fn canonical_ids(&self, ids: &[u32]) -> Vec<u32> {
+        ids.iter().map(|&id| self.aliases.canonical(id)).collect()
     }
 
     fn bitset_to_monsters(&self, bitset: &BitSet) -> Vec<&Monster> {
@@ -46,35 +201,55 @@ impl SmallWorldSearcher {
         monsters
     }
 
+    fn ids_to_bitset(&self, ids: &[u32]) -> BitSet {
+        let mut bitset = BitSet::new(self.monsters.len());
+        for id in ids {
+            if let Some(idx) = self.index_of(*id) {
+                bitset.set(idx);
+            }
+        }
+        bitset
+    }
+
+    fn ids_to_indices(&self, ids: &[u32]) -> Vec<usize> {
+        ids.iter().filter_map(|&id| self.index_of(id)).collect()
+    }
+
     fn ids_to_monsters(&self, ids: &[u32]) -> Vec<&Monster> {
         ids.iter()
-            .filter_map(|id| self.id2index.get(id).map(|&idx| &self.monsters[idx]))
+            .filter_map(|&id| self.index_of(id).map(|idx| &self.monsters[idx]))
             .collect()
     }
 
     // Find monsters that acts as bridge between all given monster.
-    pub fn find_universal_bridges(&self, ids: &[u32]) -> Option<Vec<Monster>> {
-        let monsters = self.ids_to_monsters(ids);
-
-        let bridges = search_bridge_bitset(&monsters, &self.index)?;
-        let monsters = self.bitset_to_monsters(&bridges);
+    pub fn find_universal_bridges(&self, ids: &[u32]) -> Bridges {
+        let monsters = self
+            .ids_to_indices(ids)
+            .into_iter()
+            .map(|idx| self.neighborhood(idx).into_owned())
+            .reduce(|a, b| a.and(&b))
+            .map_or(vec![], |bridges| {
+                self.bitset_to_monsters(&bridges)
+                    .into_iter()
+                    .cloned()
+                    .collect()
+            });
 
-        if monsters.is_empty() {
-            None
-        } else {
-            Some(monsters.into_iter().cloned().collect())
+        Bridges {
+            monsters,
+            diagnostics: self.diagnostics_for(ids),
         }
     }
 
     /// Find monsters that connect every source monster to every target monster.
-    pub fn find_common_bridges(&self, source: &[u32], target: &[u32]) -> Option<Vec<Monster>> {
-        let source_monsters = self.ids_to_monsters(source);
-        let target_monsters = self.ids_to_monsters(target);
+    pub fn find_common_bridges(&self, source: &[u32], target: &[u32]) -> Bridges {
+        let source_indices = self.ids_to_indices(source);
+        let target_indices = self.ids_to_indices(target);
 
         let mut common_bridges: Option<BitSet> = None;
-        for source in &source_monsters {
-            for target in &target_monsters {
-                let bridges = search_bridge_bitset(&[source, target], &self.index)?;
+        for &source in &source_indices {
+            for &target in &target_indices {
+                let bridges = self.neighborhood(source).and(&self.neighborhood(target));
                 common_bridges = match &common_bridges {
                     Some(cb) => Some(cb.and(&bridges)),
                     None => Some(bridges),
@@ -82,45 +257,168 @@ impl SmallWorldSearcher {
             }
         }
 
-        if let Some(cb) = common_bridges {
-            let monsters = self.bitset_to_monsters(&cb);
-            if monsters.is_empty() {
-                None
-            } else {
-                Some(monsters.into_iter().cloned().collect())
+        let monsters = common_bridges.map_or(vec![], |cb| {
+            self.bitset_to_monsters(&cb).into_iter().cloned().collect()
+        });
+
+        Bridges {
+            monsters,
+            diagnostics: self.diagnostics_for(source.iter().chain(target)),
+        }
+    }
+
+    /// Find every path hand -> bridge -> target for all given hand and target monsters.
+    /// Paths are grouped per hand/target pair, pairs without any bridge are left out.
+    pub fn find_paths(&self, hand_ids: &[u32], target_ids: &[u32]) -> PathGroups {
+        let hands = self.ids_to_indices(hand_ids);
+        let targets = self.ids_to_indices(target_ids);
+
+        let mut result = vec![];
+
+        for &hand_idx in &hands {
+            let hand = &self.monsters[hand_idx];
+            let hand_neighborhood = self.neighborhood(hand_idx);
+
+            for &target_idx in &targets {
+                let target = &self.monsters[target_idx];
+                let bridges = hand_neighborhood.and(&self.neighborhood(target_idx));
+
+                let paths = bridges
+                    .ones()
+                    .into_iter()
+                    .filter_map(|bridge_idx| self.path(hand, &self.monsters[bridge_idx], target))
+                    .collect::<Vec<_>>();
+
+                if !paths.is_empty() {
+                    result.push(PathGroup {
+                        hand: hand.id(),
+                        target: target.id(),
+                        paths,
+                    });
+                }
             }
-        } else {
-            None
+        }
+
+        PathGroups {
+            groups: result,
+            diagnostics: self.diagnostics_for(hand_ids.iter().chain(target_ids)),
+        }
+    }
+
+    /// Find every monster that can reach the target through at least one bridge.
+    /// If a pool is given, both the source and the bridge have to be part of it,
+    /// otherwise the whole card pool is searched. The target itself is never a source.
+    pub fn find_sources(&self, target_id: u32, pool_ids: Option<Vec<u32>>) -> Sources {
+        let diagnostics =
+            self.diagnostics_for(std::iter::once(&target_id).chain(pool_ids.iter().flatten()));
+        let Some(target_idx) = self.index_of(target_id) else {
+            return Sources {
+                sources: vec![],
+                diagnostics,
+            };
+        };
+
+        let pool_mask = match pool_ids {
+            Some(ids) => self.ids_to_bitset(&ids),
+            None => BitSet::new(self.monsters.len()).not(),
+        };
+
+        // 1st application: every bridge in the pool that connects to the target
+        let bridges = self.neighborhood(target_idx).and(&pool_mask);
+
+        // 2nd application: the union of all bridge neighborhoods are the sources
+        let mut bridges_by_source: HashMap<usize, Vec<u32>> = HashMap::new();
+        for bridge_idx in bridges.ones() {
+            let sources = self.neighborhood(bridge_idx).and(&pool_mask);
+            for source_idx in sources.ones() {
+                if source_idx == target_idx {
+                    continue;
+                }
+                bridges_by_source
+                    .entry(source_idx)
+                    .or_default()
+                    .push(self.monsters[bridge_idx].id());
+            }
+        }
+
+        let mut result = bridges_by_source
+            .into_iter()
+            .map(|(source_idx, bridges)| Source {
+                source: self.monsters[source_idx].id(),
+                bridges,
+            })
+            .collect::<Vec<_>>();
+        result.sort_by_key(|s| s.source);
+        Sources {
+            sources: result,
+            diagnostics,
         }
     }
 
     pub fn get_by_id(&self, id: u32) -> Option<Monster> {
-        self.id2index
-            .get(&id)
-            .map(|idx| self.monsters[*idx].clone())
+        self.index_of(id).map(|idx| self.monsters[idx].clone())
     }
 
     pub fn get_all(&self) -> Vec<Monster> {
-        self.monsters.iter().cloned().collect()
+        self.monsters.to_vec()
     }
 
-    /// For every monster in the pool, find to which other monsters from the pool it can link to, excluding self-links.
-    pub fn compute_links_within(&self, pool_ids: &[u32]) -> Vec<Link> {
-        let mut pool_mask = BitSet::new(self.monsters.len());
-        let mut pool_indices = vec![];
-
-        for id in pool_ids {
-            if let Some(idx) = self.id2index.get(id) {
-                pool_indices.push(*idx);
-                pool_mask.set(*idx);
-            }
+    /// Passcodes of the monsters whose names match the query best, best first.
+    /// Tolerates typos and ignores case, punctuation and hyphens.
+    pub fn search_names(&self, query: &str, limit: usize) -> Vec<u32> {
+        self.names
+            .search(query, limit)
+            .into_iter()
+            .map(|idx| self.monsters[idx].id())
+            .collect()
+    }
+
+    /// Passcode of the monster with the given name, allowing for small typos,
+    /// e.g. to import a plain-text decklist. `None` if no name is close enough.
+    pub fn resolve_name(&self, name: &str) -> Option<u32> {
+        self.names
+            .best(name, NAME_SIMILARITY)
+            .map(|idx| self.monsters[idx].id())
+    }
+
+    /// Parse a filter expression like `CardFilter.parse`, attributes and types this version
+    /// does not know are accepted too if the card database has them.
+    pub fn parse_filter(&self, query: &str) -> Result<CardFilter, FilterError> {
+        CardFilter::parse_with_index(query, &self.index)
+    }
+
+    /// Every monster of the card pool that matches the filter.
+    pub fn search(&self, filter: &CardFilter) -> Vec<Monster> {
+        self.bitset_to_monsters(&filter.evaluate(&self.monsters, &self.index))
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// The passcodes of monsters that match the filter, in the given order,
+    /// e.g. to narrow down bridge results. Passcodes not in the database are dropped.
+    pub fn filter_ids(&self, filter: &CardFilter, ids: &[u32]) -> FilteredIds {
+        let matches = filter.evaluate(&self.monsters, &self.index);
+        FilteredIds {
+            ids: ids
+                .iter()
+                .copied()
+                .filter(|&id| self.index_of(id).is_some_and(|idx| matches.get(idx)))
+                .collect(),
+            diagnostics: self.diagnostics_for(ids),
         }
+    }
+
+    /// For every monster in the pool, find to which other monsters from the pool it can link to, excluding self-links.
+    pub fn compute_links_within(&self, pool_ids: &[u32]) -> Links {
+        let pool_mask = self.ids_to_bitset(pool_ids);
+        // links are listed in the order of the pool
+        let pool_indices = self.ids_to_indices(pool_ids);
 
         let mut result = vec![];
 
         for start_idx in pool_indices.iter() {
-            let start_neigborhood =
-                find_neighborhood_bitset(&self.monsters[*start_idx], &self.index);
+            let start_neigborhood = self.neighborhood(*start_idx);
 
             // intersect N(start) with pool -> gives us the 1st step of small world
             let step_1 = start_neigborhood.and(&pool_mask);
@@ -128,7 +426,7 @@ impl SmallWorldSearcher {
             for bridge_idx in step_1.ones() {
                 let bridge_neighborhood =
                 // intersect N(bridge) with pool -> gives us the 2nd step of small world
-                    find_neighborhood_bitset(&self.monsters[bridge_idx], &self.index);
+                    self.neighborhood(bridge_idx);
                 let step_2 = bridge_neighborhood.and(&pool_mask);
                 // every monster in step_2 is reachable from start via bridge
                 for target_idx in step_2.ones() {
@@ -145,58 +443,555 @@ impl SmallWorldSearcher {
             }
         }
 
-        result
+        Links {
+            links: result,
+            diagnostics: self.diagnostics_for(pool_ids),
+        }
     }
 
-    /// Given two monsters m1 and m2, lookup the first property that connects them.
-    /// Returns a String or None if they are not connected.
-    pub fn compute_connecting_property(&self, m1: u32, m2: u32) -> Option<String> {
-        let index_1 = self.id2index.get(&m1).unwrap();
-        let index_2 = self.id2index.get(&m2).unwrap();
-
-        let monster_1 = &self.monsters[*index_1];
-        let monster_2 = &self.monsters[*index_2];
-
-        let sets_1 = [
-            self.index.by_attribute.get(&monster_1.attribute()).unwrap(),
-            self.index.by_level.get(&monster_1.level()).unwrap(),
-            self.index.by_type.get(&monster_1.r#type()).unwrap(),
-            self.index.by_atk.get(&monster_1.atk()).unwrap(),
-            self.index.by_def.get(&monster_1.def()).unwrap(),
-        ];
-        let sets_2 = [
-            self.index.by_attribute.get(&monster_2.attribute()).unwrap(),
-            self.index.by_level.get(&monster_2.level()).unwrap(),
-            self.index.by_type.get(&monster_2.r#type()).unwrap(),
-            self.index.by_atk.get(&monster_2.atk()).unwrap(),
-            self.index.by_def.get(&monster_2.def()).unwrap(),
-        ];
+    /// Find every pool monster reachable from the hand monster with at most `max_activations`
+    /// consecutive Small World resolutions, where each target is revealed for the next activation.
+    /// Every target is reported with the minimum number of activations and one shortest path.
+    pub fn find_chains(&self, hand_id: u32, pool_ids: &[u32], max_activations: u32) -> Chains {
+        let diagnostics = self.diagnostics_for(std::iter::once(&hand_id).chain(pool_ids));
+        let Some(hand_idx) = self.index_of(hand_id) else {
+            return Chains {
+                chains: vec![],
+                diagnostics,
+            };
+        };
+        let pool_mask = self.ids_to_bitset(pool_ids);
 
-        for (i, (s1, s2)) in sets_1.iter().zip(sets_2.iter()).enumerate() {
-            if s1.get(*index_2) && s2.get(*index_1) {
-                return match i {
-                    0 => Some(format!("Attribute: {:?}", monster_1.attribute())),
-                    1 => Some(format!("Level: {}", monster_1.level())),
-                    2 => Some(format!("Type: {:?}", monster_1.r#type())),
-                    3 => Some(format!(
-                        "ATK: {}",
-                        monster_1.atk().map_or("?".into(), |v| v.to_string())
-                    )),
-                    4 => Some(format!(
-                        "DEF: {}",
-                        monster_1.def().map_or("?".into(), |v| v.to_string())
-                    )),
-                    _ => panic!("unreachable"),
-                };
+        // target -> (revealed monster, bridge) of the activation that first reached it
+        let mut parents: HashMap<usize, (usize, usize)> = HashMap::new();
+        let mut visited = BitSet::new(self.monsters.len());
+        visited.set(hand_idx);
+        let mut frontier = visited.ones();
+        let mut result = vec![];
+
+        for activations in 1..=max_activations {
+            let mut next = BitSet::new(self.monsters.len());
+
+            for &reveal_idx in &frontier {
+                let step_1 = self.neighborhood(reveal_idx).and(&pool_mask);
+                for bridge_idx in step_1.ones() {
+                    let step_2 = self
+                        .neighborhood(bridge_idx)
+                        .and(&pool_mask)
+                        .and(&visited.or(&next).not());
+                    for target_idx in step_2.ones() {
+                        parents.insert(target_idx, (reveal_idx, bridge_idx));
+                        next.set(target_idx);
+                    }
+                }
+            }
+
+            frontier = next.ones();
+            if frontier.is_empty() {
+                break;
+            }
+            visited = visited.or(&next);
+
+            for &target_idx in &frontier {
+                let mut path = vec![target_idx];
+                while let Some(&(reveal_idx, bridge_idx)) = parents.get(path.last().unwrap()) {
+                    path.push(bridge_idx);
+                    path.push(reveal_idx);
+                }
+                path.reverse();
+
+                result.push(Chain {
+                    target: self.monsters[target_idx].id(),
+                    activations,
+                    path: path
+                        .into_iter()
+                        .map(|idx| self.monsters[idx].id())
+                        .collect(),
+                });
+            }
+        }
+
+        Chains {
+            chains: result,
+            diagnostics,
+        }
+    }
+
+    /// List every reveal -> bridge -> target line of a concrete hand. Bridge and target have to be
+    /// cards left in the main deck of `deck`, so the same passcode can only be both with two copies.
+    pub fn find_hand_lines(&self, hand_ids: &[u32], deck: &Deck) -> HandLines {
+        let diagnostics =
+            self.diagnostics_for(without_small_world(hand_ids.iter().chain(main_ids(deck))));
+        let deck = &self.aliases.canonical_deck(deck);
+        let deck_ids = deck.unique_ids(Section::Main);
+        let deck_mask = self.ids_to_bitset(&deck_ids);
+
+        let mut reveals = self.ids_to_indices(hand_ids);
+        reveals.sort();
+        reveals.dedup();
+
+        let mut lines = vec![];
+        for reveal_idx in reveals {
+            let step_1 = self.neighborhood(reveal_idx).and(&deck_mask);
+            for bridge_idx in step_1.ones() {
+                let bridge = &self.monsters[bridge_idx];
+                let step_2 = self.neighborhood(bridge_idx).and(&deck_mask);
+                for target_idx in step_2.ones() {
+                    if target_idx == bridge_idx && deck.count(Section::Main, bridge.id()) < 2 {
+                        continue;
+                    }
+                    lines.extend(self.path(
+                        &self.monsters[reveal_idx],
+                        bridge,
+                        &self.monsters[target_idx],
+                    ));
+                }
+            }
+        }
+
+        HandLines {
+            has_small_world: hand_ids.contains(&SMALL_WORLD_ID),
+            lines,
+            diagnostics,
+        }
+    }
+
+    /// Rank monsters outside the deck by how many new hand/target pairs of the deck
+    /// they would connect if added as a bridge. Pairs are unordered and only pairs
+    /// that can not already be connected through a bridge in the deck are counted.
+    pub fn recommend_bridges(&self, deck_ids: &[u32], limit: usize) -> Recommendations {
+        let deck_mask = self.ids_to_bitset(deck_ids);
+        let reachable = self.reachable_within(&deck_mask);
+
+        let mut result = self
+            .bridge_candidates(&deck_mask)
+            .ones()
+            .into_iter()
+            .map(|bridge_idx| self.recommendation(bridge_idx, &deck_mask, &reachable))
+            .filter(|r| r.new_connections > 0)
+            .collect::<Vec<_>>();
+
+        result.sort_by(|a, b| {
+            b.new_connections
+                .cmp(&a.new_connections)
+                .then(a.bridge.cmp(&b.bridge))
+        });
+        result.truncate(limit);
+        Recommendations {
+            recommendations: result,
+            diagnostics: self.diagnostics_for(deck_ids),
+        }
+    }
+
+    /// Greedily select up to k bridges to add to the deck. Each step picks the monster
+    /// connecting the most pairs not covered by the deck or a previously selected bridge,
+    /// so `new_connections` is the marginal gain of every pick.
+    pub fn select_bridges(&self, deck_ids: &[u32], k: usize) -> Recommendations {
+        let deck_mask = self.ids_to_bitset(deck_ids);
+        let mut reachable = self.reachable_within(&deck_mask);
+        let mut candidates = self.bridge_candidates(&deck_mask).ones();
+
+        let mut result = vec![];
+
+        for _ in 0..k {
+            let Some((position, best)) = candidates
+                .iter()
+                .map(|&bridge_idx| self.recommendation(bridge_idx, &deck_mask, &reachable))
+                .enumerate()
+                .filter(|(_, r)| r.new_connections > 0)
+                .max_by(|(_, a), (_, b)| {
+                    a.new_connections
+                        .cmp(&b.new_connections)
+                        .then(b.bridge.cmp(&a.bridge))
+                })
+            else {
+                break;
+            };
+
+            // every pair around the selected bridge is now covered
+            let connected = self.ids_to_bitset(&best.connects);
+            for idx in connected.ones() {
+                let reach = reachable.get_mut(&idx).unwrap();
+                *reach = reach.or(&connected);
+                reach.clear(idx);
+            }
+
+            candidates.remove(position);
+            result.push(best);
+        }
+
+        Recommendations {
+            recommendations: result,
+            diagnostics: self.diagnostics_for(deck_ids),
+        }
+    }
+
+    /// For every monster in the pool, the pool monsters it can reach through a bridge in the pool, excluding itself.
+    fn reachable_within(&self, pool_mask: &BitSet) -> HashMap<usize, BitSet> {
+        pool_mask
+            .ones()
+            .into_iter()
+            .map(|start_idx| {
+                let step_1 = self.neighborhood(start_idx).and(pool_mask);
+                let mut reach = step_1
+                    .ones()
+                    .into_iter()
+                    .fold(BitSet::new(self.monsters.len()), |acc, bridge_idx| {
+                        acc.or(&self.neighborhood(bridge_idx))
+                    })
+                    .and(pool_mask);
+                reach.clear(start_idx);
+                (start_idx, reach)
+            })
+            .collect()
+    }
+
+    /// Monsters outside the pool that connect to at least one monster of the pool.
+    fn bridge_candidates(&self, pool_mask: &BitSet) -> BitSet {
+        pool_mask
+            .ones()
+            .into_iter()
+            .fold(BitSet::new(self.monsters.len()), |acc, idx| {
+                acc.or(&self.neighborhood(idx))
+            })
+            .and(&pool_mask.not())
+    }
+
+    fn recommendation(
+        &self,
+        bridge_idx: usize,
+        pool_mask: &BitSet,
+        reachable: &HashMap<usize, BitSet>,
+    ) -> Recommendation {
+        let connected = self.neighborhood(bridge_idx).and(pool_mask);
+
+        // count ordered pairs that are not reachable yet, every pair is seen from both ends
+        let new_connections = connected
+            .ones()
+            .into_iter()
+            .map(|idx| {
+                let mut new_targets = connected.and(&reachable[&idx].not());
+                new_targets.clear(idx);
+                new_targets.count_ones()
+            })
+            .sum::<u32>()
+            / 2;
+
+        Recommendation {
+            bridge: self.monsters[bridge_idx].id(),
+            new_connections,
+            connects: connected
+                .ones()
+                .into_iter()
+                .map(|idx| self.monsters[idx].id())
+                .collect(),
+        }
+    }
+
+    /// Neighborhood of the monster at idx, looked up if the adjacency is precomputed.
+    fn neighborhood(&self, idx: usize) -> Cow<'_, BitSet> {
+        match self.adjacency.get() {
+            Some(adjacency) => Cow::Borrowed(&adjacency[idx]),
+            None => Cow::Owned(find_neighborhood_bitset(
+                &self.monsters[idx],
+                &self.index,
+                &self.rule,
+            )),
+        }
+    }
+
+    /// Whether the two monsters are connected under the current rule,
+    /// `None` if one of them is not in the database.
+    pub(crate) fn connected(&self, id1: u32, id2: u32) -> Option<bool> {
+        let idx1 = self.index_of(id1)?;
+        let idx2 = self.index_of(id2)?;
+        Some(self.neighborhood(idx1).get(idx2))
+    }
+
+    fn path(&self, hand: &Monster, bridge: &Monster, target: &Monster) -> Option<PathExplanation> {
+        Some(PathExplanation {
+            hand: hand.id(),
+            bridge: bridge.id(),
+            target: target.id(),
+            hand_to_bridge: self.hop(hand, bridge)?,
+            bridge_to_target: self.hop(bridge, target)?,
+        })
+    }
+
+    fn hop(&self, from: &Monster, to: &Monster) -> Option<Hop> {
+        let shared = self
+            .rule
+            .shared_properties(from, to, self.index.unknown_stats);
+        if self.rule.accepts(shared.len() as u32) {
+            Some(Hop::new(shared))
+        } else {
+            None
+        }
+    }
+
+    /// Like `compute_links_within`, but symmetric links are merged and grouped by source/target pair.
+    /// Each unordered pair appears once, with the monster that comes first in the card pool as source.
+    pub fn compute_link_groups_within(&self, pool_ids: &[u32]) -> LinkGroups {
+        let pool_mask = self.ids_to_bitset(pool_ids);
+
+        let mut bridges_by_pair: BTreeMap<(usize, usize), Vec<u32>> = BTreeMap::new();
+
+        for start_idx in pool_mask.ones() {
+            let step_1 = self.neighborhood(start_idx).and(&pool_mask);
+
+            for bridge_idx in step_1.ones() {
+                let step_2 = self.neighborhood(bridge_idx).and(&pool_mask);
+                // the reverse direction is found when starting from the target
+                for target_idx in step_2.ones().into_iter().filter(|&t| t > start_idx) {
+                    bridges_by_pair
+                        .entry((start_idx, target_idx))
+                        .or_default()
+                        .push(self.monsters[bridge_idx].id());
+                }
+            }
+        }
+
+        let mut groups = LinkGroups {
+            sources: vec![],
+            targets: vec![],
+            bridge_offsets: vec![0],
+            bridges: vec![],
+            diagnostics: self.diagnostics_for(pool_ids),
+        };
+        for ((source_idx, target_idx), bridges) in bridges_by_pair {
+            groups.sources.push(self.monsters[source_idx].id());
+            groups.targets.push(self.monsters[target_idx].id());
+            groups.bridges.extend(bridges);
+            groups.bridge_offsets.push(groups.bridges.len() as u32);
+        }
+        groups
+    }
+
+    /// Exact probability to open Small World together with a monster that reaches the targets,
+    /// for a hand of the given size drawn from the main deck. See `probability::opening_odds`.
+    pub fn opening_odds(&self, deck: &Deck, hand_size: u32, target_ids: &[u32]) -> OpeningOdds {
+        let targets = self.canonical_ids(target_ids);
+        let mut diagnostics =
+            self.diagnostics_for(without_small_world(main_ids(deck).chain(target_ids)));
+        let graph = self.deck_graph(deck, &[], &mut diagnostics);
+        opening_odds(&graph, hand_size, &targets).with_diagnostics(diagnostics)
+    }
+
+    /// Estimate the opening odds by shuffling the main deck `trials` times, taking into account
+    /// that bridges and targets drawn into the hand are no longer in the deck.
+    /// The same seed always gives the same result.
+    pub fn simulate_openings(
+        &self,
+        deck: &Deck,
+        hand_size: u32,
+        target_ids: &[u32],
+        trials: u32,
+        seed: u64,
+    ) -> SimulationResult {
+        let targets = self.canonical_ids(target_ids);
+        let mut diagnostics =
+            self.diagnostics_for(without_small_world(main_ids(deck).chain(target_ids)));
+        let graph = self.deck_graph(deck, &[], &mut diagnostics);
+        simulate(&graph, hand_size, &targets, trials, seed).with_diagnostics(diagnostics)
+    }
+
+    /// Search the copies of the candidate monsters to add to the main deck of the core,
+    /// at most `config.budget` cards, that maximize the mean chance to reach each target.
+    /// Candidates that are not in the database are ignored.
+    pub fn optimize_deck(
+        &self,
+        core: &Deck,
+        candidate_ids: &[u32],
+        target_ids: &[u32],
+        config: &OptimizerConfig,
+    ) -> OptimizedDeck {
+        let mut diagnostics = self.diagnostics_for(without_small_world(
+            main_ids(core).chain(candidate_ids).chain(target_ids),
+        ));
+        let mut graph = self.deck_graph(core, candidate_ids, &mut diagnostics);
+        let candidates = self
+            .canonical_ids(candidate_ids)
+            .into_iter()
+            .filter_map(|id| graph.position(id))
+            .collect::<Vec<_>>();
+        let targets = self.canonical_ids(target_ids);
+        let before = graph.copies().to_vec();
+        let (coverage, evaluations) = optimize(&mut graph, &candidates, &targets, config);
+
+        let mut deck = self.aliases.canonical_deck(core);
+        for (i, (&copies, &old)) in graph.copies().iter().zip(&before).enumerate() {
+            deck.add(Section::Main, graph.ids()[i], copies - old);
+        }
+        OptimizedDeck::new(deck, coverage, evaluations, diagnostics)
+    }
+
+    /// The main deck as local graph for the probability calculations,
+    /// the candidates that are not in the deck are added with zero copies.
+    /// Monsters past `DeckGraph::MAX_MONSTERS` are left out and reported in the diagnostics,
+    /// their copies in the deck count as other cards.
+    fn deck_graph(
+        &self,
+        deck: &Deck,
+        candidates: &[u32],
+        diagnostics: &mut Diagnostics,
+    ) -> DeckGraph {
+        let deck = self.aliases.canonical_deck(deck);
+        let mut monsters = vec![];
+        let mut indices = vec![];
+        let mut other = 0;
+        let mut over_limit = |id| {
+            diagnostics.push(IgnoredId::new(id, IgnoreReason::OverMonsterLimit, None));
+        };
+        for &(id, copies) in deck.entries(Section::Main) {
+            match self.id2index.get(&id) {
+                Some(&idx) if indices.len() < DeckGraph::MAX_MONSTERS => {
+                    monsters.push((id, copies));
+                    indices.push(idx);
+                }
+                Some(_) => {
+                    over_limit(id);
+                    other += copies;
+                }
+                None if id != SMALL_WORLD_ID => other += copies,
+                None => {}
+            }
+        }
+        for &id in candidates {
+            let Some(idx) = self.index_of(id) else {
+                continue;
+            };
+            if indices.contains(&idx) {
+                continue;
+            }
+            if indices.len() < DeckGraph::MAX_MONSTERS {
+                monsters.push((self.monsters[idx].id(), 0));
+                indices.push(idx);
+            } else {
+                over_limit(self.monsters[idx].id());
+            }
+        }
+
+        let neighborhoods = indices
+            .iter()
+            .map(|&idx| self.neighborhood(idx))
+            .collect::<Vec<_>>();
+
+        DeckGraph::new(
+            monsters,
+            deck.count(Section::Main, SMALL_WORLD_ID),
+            other,
+            |i, j| neighborhoods[i].get(indices[j]),
+        )
+    }
+
+    /// Graph metrics of the deck graph built by `compute_links_within`.
+    pub fn compute_deck_stats(&self, pool_ids: &[u32]) -> DeckGraphStats {
+        let pool_mask = self.ids_to_bitset(pool_ids);
+        let reachable = self.reachable_within(&pool_mask);
+        let cards = pool_mask.ones();
+
+        let mut reached = BitSet::new(self.monsters.len());
+        for reach in reachable.values() {
+            reached = reached.or(reach);
+        }
+
+        // connected components, following links breadth first
+        let mut component = HashMap::new();
+        let mut components = 0;
+        for &card in &cards {
+            if component.contains_key(&card) {
+                continue;
+            }
+            let id = components;
+            components += 1;
+            let mut queue = vec![card];
+            component.insert(card, id);
+            while let Some(current) = queue.pop() {
+                for next in reachable[&current].ones() {
+                    if let Entry::Vacant(entry) = component.entry(next) {
+                        entry.insert(id);
+                        queue.push(next);
+                    }
+                }
             }
         }
 
-        None
+        // every pair splits one unit of credit over all of its bridges
+        let groups = self.compute_link_groups_within(pool_ids);
+        let mut bridge_share: BTreeMap<u32, f64> = BTreeMap::new();
+        let mut critical: BTreeMap<u32, u32> = BTreeMap::new();
+        for (_, _, bridges) in groups.iter() {
+            for &bridge in bridges {
+                *bridge_share.entry(bridge).or_default() += 1.0 / bridges.len() as f64;
+            }
+            if let [bridge] = bridges {
+                *critical.entry(*bridge).or_default() += 1;
+            }
+        }
+
+        let id = |idx: &usize| self.monsters[*idx].id();
+        DeckGraphStats {
+            ids: cards.iter().map(id).collect(),
+            degrees: cards.iter().map(|c| reachable[c].count_ones()).collect(),
+            components: cards.iter().map(|c| component[c]).collect(),
+            bridges: bridge_share.keys().copied().collect(),
+            bridge_share: bridge_share.values().copied().collect(),
+            critical_bridges: critical.keys().copied().collect(),
+            critical_pairs: critical.values().copied().collect(),
+            unreachable: cards.iter().filter(|&&c| !reached.get(c)).map(id).collect(),
+            dead_ends: cards
+                .iter()
+                .filter(|c| reachable[c].count_ones() == 0)
+                .map(id)
+                .collect(),
+            diagnostics: self.diagnostics_for(pool_ids),
+        }
+    }
+
+    /// Given two monsters m1 and m2, lookup the first property that connects them.
+    /// Returns a String or None if they are not connected, see `diagnose` for unknown passcodes.
+    pub fn compute_connecting_property(&self, m1: u32, m2: u32) -> Option<String> {
+        let monster_1 = self.ids_to_monsters(&[m1]).pop()?;
+        let monster_2 = self.ids_to_monsters(&[m2]).pop()?;
+
+        self.rule
+            .shared_properties(monster_1, monster_2, self.index.unknown_stats)
+            .first()
+            .map(connecting_label)
+    }
+
+    /// Explain the path hand -> bridge -> target by the properties shared on each hop.
+    /// Returns None if one of the monsters is unknown or a hop is not a valid connection.
+    pub fn explain_path(&self, hand: u32, bridge: u32, target: u32) -> Option<PathExplanation> {
+        let hand = self.ids_to_monsters(&[hand]).pop()?;
+        let bridge = self.ids_to_monsters(&[bridge]).pop()?;
+        let target = self.ids_to_monsters(&[target]).pop()?;
+
+        self.path(hand, bridge, target)
+    }
+}
+
+/// The label shown by the web for a connecting property, known types keep their
+/// variant name (e.g. "Type: BeastWarrior") as the frontend matches on it.
+fn connecting_label(property: &ConnectingProperty) -> String {
+    match property {
+        ConnectingProperty::Type(r#type) if !r#type.is_unknown() => format!("Type: {:?}", r#type),
+        property => property.to_string(),
     }
 }
 
+/// The distinct passcodes of the main deck.
+fn main_ids(deck: &Deck) -> impl Iterator<Item = &u32> {
+    deck.entries(Section::Main).iter().map(|(id, _)| id)
+}
+
+/// Small World is part of decks and hands, but never a monster of a query.
+fn without_small_world<'a>(ids: impl Iterator<Item = &'a u32>) -> impl Iterator<Item = &'a u32> {
+    ids.filter(|&&id| id != SMALL_WORLD_ID)
+}
+
 #[wasm_bindgen]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Link {
     start: Monster,
     bridge: Monster,
@@ -221,30 +1016,719 @@ impl Link {
     }
 }
 
-// test
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_common_bridges() {
-        let searcher = SmallWorldSearcher::from_csv(include_str!("../testing_data.csv"));
-        let source = [86988864];
-        let target = [23771716];
+/// The links within a pool.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Links {
+    links: Vec<Link>,
+    diagnostics: Diagnostics,
+}
 
-        let bridges = searcher.find_common_bridges(&source, &target).unwrap();
+#[wasm_bindgen]
+impl Links {
+    #[wasm_bindgen(getter)]
+    pub fn links(&self) -> Vec<Link> {
+        self.links.clone()
+    }
 
-        assert_eq!(bridges.len(), 1);
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
     }
+}
 
-    #[test]
-    fn test_compute_links_within() {
-        let searcher = SmallWorldSearcher::from_csv(include_str!("../testing_data.csv"));
+/// The bridges found for the monsters of a query, empty if there are none.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Bridges {
+    monsters: Vec<Monster>,
+    diagnostics: Diagnostics,
+}
+
+#[wasm_bindgen]
+impl Bridges {
+    #[wasm_bindgen(getter)]
+    pub fn monsters(&self) -> Vec<Monster> {
+        self.monsters.clone()
+    }
+
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
+    }
+}
+
+/// The passcodes that match a filter, in the order of the query.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct FilteredIds {
+    ids: Vec<u32>,
+    diagnostics: Diagnostics,
+}
+
+#[wasm_bindgen]
+impl FilteredIds {
+    #[wasm_bindgen(getter)]
+    pub fn ids(&self) -> Vec<u32> {
+        self.ids.clone()
+    }
+
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
+    }
+}
+
+/// Metrics of a deck graph, per card lists are aligned with `ids`.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct DeckGraphStats {
+    ids: Vec<u32>,
+    degrees: Vec<u32>,
+    components: Vec<u32>,
+    bridges: Vec<u32>,
+    bridge_share: Vec<f64>,
+    critical_bridges: Vec<u32>,
+    critical_pairs: Vec<u32>,
+    unreachable: Vec<u32>,
+    dead_ends: Vec<u32>,
+    diagnostics: Diagnostics,
+}
+
+#[wasm_bindgen]
+impl DeckGraphStats {
+    /// every card of the deck known to the searcher
+    #[wasm_bindgen(getter)]
+    pub fn ids(&self) -> Vec<u32> {
+        self.ids.clone()
+    }
+
+    /// number of cards each card can reach
+    #[wasm_bindgen(getter)]
+    pub fn degrees(&self) -> Vec<u32> {
+        self.degrees.clone()
+    }
+
+    /// connected component of each card, numbered from 0
+    #[wasm_bindgen(getter)]
+    pub fn components(&self) -> Vec<u32> {
+        self.components.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn component_count(&self) -> u32 {
+        self.components.iter().max().map_or(0, |max| max + 1)
+    }
+
+    /// every card that acts as bridge for at least one pair
+    #[wasm_bindgen(getter)]
+    pub fn bridges(&self) -> Vec<u32> {
+        self.bridges.clone()
+    }
+
+    /// share of each bridge in connecting the pairs of the deck: every unordered pair that can
+    /// be linked counts 1, split evenly over all of its bridges. Unlike graph betweenness,
+    /// only the direct reveal -> bridge -> target paths are counted, not shortest paths.
+    #[wasm_bindgen(getter)]
+    pub fn bridge_share(&self) -> Vec<f64> {
+        self.bridge_share.clone()
+    }
+
+    /// bridges that are the only route for at least one pair
+    #[wasm_bindgen(getter)]
+    pub fn critical_bridges(&self) -> Vec<u32> {
+        self.critical_bridges.clone()
+    }
+
+    /// number of pairs each critical bridge is the only route for
+    #[wasm_bindgen(getter)]
+    pub fn critical_pairs(&self) -> Vec<u32> {
+        self.critical_pairs.clone()
+    }
+
+    /// cards no other card can reach
+    #[wasm_bindgen(getter)]
+    pub fn unreachable(&self) -> Vec<u32> {
+        self.unreachable.clone()
+    }
+
+    /// cards that can not reach any other card
+    #[wasm_bindgen(getter)]
+    pub fn dead_ends(&self) -> Vec<u32> {
+        self.dead_ends.clone()
+    }
+
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
+    }
+}
+
+/// Links within a pool grouped by source/target pair, stored as flat arrays.
+///
+/// Group i connects `sources[i]` and `targets[i]` through the bridges
+/// `bridges[bridge_offsets[i]..bridge_offsets[i + 1]]`.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct LinkGroups {
+    sources: Vec<u32>,
+    targets: Vec<u32>,
+    bridge_offsets: Vec<u32>,
+    bridges: Vec<u32>,
+    diagnostics: Diagnostics,
+}
+
+impl LinkGroups {
+    /// Iterate over (source, target, bridges) of every group.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, u32, &[u32])> {
+        (0..self.len()).map(|i| {
+            let start = self.bridge_offsets[i] as usize;
+            let end = self.bridge_offsets[i + 1] as usize;
+            (self.sources[i], self.targets[i], &self.bridges[start..end])
+        })
+    }
+}
+
+#[wasm_bindgen]
+impl LinkGroups {
+    /// number of groups
+    #[wasm_bindgen(getter)]
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn sources(&self) -> Vec<u32> {
+        self.sources.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn targets(&self) -> Vec<u32> {
+        self.targets.clone()
+    }
+
+    /// start of every group in `bridges`, followed by the total number of bridges
+    #[wasm_bindgen(getter)]
+    pub fn bridge_offsets(&self) -> Vec<u32> {
+        self.bridge_offsets.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bridges(&self) -> Vec<u32> {
+        self.bridges.clone()
+    }
+
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
+    }
+}
+
+/// One step of a Small World path, described by the properties the two monsters share.
+/// Under the default rule this is exactly one property.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Hop {
+    connecting_properties: Vec<ConnectingProperty>,
+}
+
+impl Hop {
+    fn new(connecting_properties: Vec<ConnectingProperty>) -> Self {
+        Hop {
+            connecting_properties,
+        }
+    }
+
+    pub fn connecting_properties(&self) -> &[ConnectingProperty] {
+        &self.connecting_properties
+    }
+}
+
+#[wasm_bindgen]
+impl Hop {
+    #[wasm_bindgen(getter)]
+    pub fn properties(&self) -> Vec<Property> {
+        self.connecting_properties
+            .iter()
+            .map(|p| p.property())
+            .collect()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn values(&self) -> Vec<String> {
+        self.connecting_properties
+            .iter()
+            .map(|p| p.value())
+            .collect()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct PathExplanation {
+    hand: u32,
+    bridge: u32,
+    target: u32,
+    hand_to_bridge: Hop,
+    bridge_to_target: Hop,
+}
+
+#[wasm_bindgen]
+impl PathExplanation {
+    #[wasm_bindgen(getter)]
+    pub fn hand(&self) -> u32 {
+        self.hand
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bridge(&self) -> u32 {
+        self.bridge
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn target(&self) -> u32 {
+        self.target
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn hand_to_bridge(&self) -> Hop {
+        self.hand_to_bridge.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bridge_to_target(&self) -> Hop {
+        self.bridge_to_target.clone()
+    }
+}
+
+/// All paths between one hand monster and one target monster.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct PathGroup {
+    hand: u32,
+    target: u32,
+    paths: Vec<PathExplanation>,
+}
+
+#[wasm_bindgen]
+impl PathGroup {
+    #[wasm_bindgen(getter)]
+    pub fn hand(&self) -> u32 {
+        self.hand
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn target(&self) -> u32 {
+        self.target
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn paths(&self) -> Vec<PathExplanation> {
+        self.paths.clone()
+    }
+}
+
+/// The paths of every hand/target pair that has at least one bridge.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct PathGroups {
+    groups: Vec<PathGroup>,
+    diagnostics: Diagnostics,
+}
+
+#[wasm_bindgen]
+impl PathGroups {
+    #[wasm_bindgen(getter)]
+    pub fn groups(&self) -> Vec<PathGroup> {
+        self.groups.clone()
+    }
+
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
+    }
+}
+
+/// A monster that can reach a target, together with the bridges that connect them.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Source {
+    source: u32,
+    bridges: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl Source {
+    #[wasm_bindgen(getter)]
+    pub fn source(&self) -> u32 {
+        self.source
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bridges(&self) -> Vec<u32> {
+        self.bridges.clone()
+    }
+}
+
+/// Every monster that can reach a target, ordered by passcode.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Sources {
+    sources: Vec<Source>,
+    diagnostics: Diagnostics,
+}
+
+#[wasm_bindgen]
+impl Sources {
+    #[wasm_bindgen(getter)]
+    pub fn sources(&self) -> Vec<Source> {
+        self.sources.clone()
+    }
+
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
+    }
+}
+
+/// Every Small World line available from a concrete hand.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct HandLines {
+    has_small_world: bool,
+    lines: Vec<PathExplanation>,
+    diagnostics: Diagnostics,
+}
+
+#[wasm_bindgen]
+impl HandLines {
+    /// whether Small World is in the hand, without it none of the lines can be played
+    #[wasm_bindgen(getter)]
+    pub fn has_small_world(&self) -> bool {
+        self.has_small_world
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn lines(&self) -> Vec<PathExplanation> {
+        self.lines.clone()
+    }
+
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
+    }
+}
+
+/// A target reached by consecutive Small World activations.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Chain {
+    target: u32,
+    activations: u32,
+    path: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl Chain {
+    #[wasm_bindgen(getter)]
+    pub fn target(&self) -> u32 {
+        self.target
+    }
+
+    /// minimum number of Small World activations needed to reach the target
+    #[wasm_bindgen(getter)]
+    pub fn activations(&self) -> u32 {
+        self.activations
+    }
+
+    /// hand, bridge, target of the 1st activation, bridge, target of the 2nd activation, ...
+    #[wasm_bindgen(getter)]
+    pub fn path(&self) -> Vec<u32> {
+        self.path.clone()
+    }
+}
+
+/// Every target reached by consecutive Small World activations, fewest activations first.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Chains {
+    chains: Vec<Chain>,
+    diagnostics: Diagnostics,
+}
+
+#[wasm_bindgen]
+impl Chains {
+    #[wasm_bindgen(getter)]
+    pub fn chains(&self) -> Vec<Chain> {
+        self.chains.clone()
+    }
+
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
+    }
+}
+
+/// A monster suggested as an additional bridge for a deck.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Recommendation {
+    bridge: u32,
+    new_connections: u32,
+    connects: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl Recommendation {
+    #[wasm_bindgen(getter)]
+    pub fn bridge(&self) -> u32 {
+        self.bridge
+    }
+
+    /// number of unordered hand/target pairs the bridge newly connects
+    #[wasm_bindgen(getter)]
+    pub fn new_connections(&self) -> u32 {
+        self.new_connections
+    }
+
+    /// deck monsters the bridge connects to
+    #[wasm_bindgen(getter)]
+    pub fn connects(&self) -> Vec<u32> {
+        self.connects.clone()
+    }
+}
+
+/// The bridges suggested for a deck, best first.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Recommendations {
+    recommendations: Vec<Recommendation>,
+    diagnostics: Diagnostics,
+}
+
+#[wasm_bindgen]
+impl Recommendations {
+    #[wasm_bindgen(getter)]
+    pub fn recommendations(&self) -> Vec<Recommendation> {
+        self.recommendations.clone()
+    }
+
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
+    }
+}
+
+// test
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monster::{Attribute, Type};
+    use crate::optimizer::OptimizerStrategy;
+    use crate::probability::{HAND_SIZE_FIRST, HAND_SIZE_SECOND, binomial};
+
+    #[test]
+    fn test_common_bridges() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let source = [86988864];
+        let target = [23771716];
+
+        let bridges = searcher.find_common_bridges(&source, &target).monsters();
+
+        assert_eq!(bridges.len(), 1);
+    }
+
+    #[test]
+    fn test_search() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let filter = CardFilter::parse("attr:LIGHT lvl:1 type:Spellcaster").unwrap();
+        let mut ids = searcher
+            .search(&filter)
+            .iter()
+            .map(|m| m.id())
+            .collect::<Vec<_>>();
+        ids.sort();
+        assert_eq!(ids, vec![8240199, 17947697, 97268402]);
+
+        // bridges of Lacooda, narrowed down to the ones with DEF 0
+        let bridges = searcher
+            .find_universal_bridges(&[86988864])
+            .monsters()
+            .iter()
+            .map(|m| m.id())
+            .collect::<Vec<_>>();
+        let filter = CardFilter::parse("def=0").unwrap();
+        let filtered = searcher.filter_ids(&filter, &bridges).ids();
+        assert!(filtered.contains(&63198739));
+        assert!(filtered.len() < bridges.len());
+        assert_eq!(
+            searcher.filter_ids(&filter, &[1, 63198739]).ids(),
+            vec![63198739]
+        );
+    }
+
+    #[test]
+    fn test_filter_frames() {
+        let searcher = SmallWorldSearcher::try_from_csv(
+            "id,name,attribute,level,type,atk,def,frame,abilities\n\
+            1,Hand,EARTH,3,Beast,500,1500,effect,\n\
+            2,Normal Bridge,DARK,3,Fiend,100,200,normal,\n\
+            3,Effect Bridge,DARK,5,Beast,100,200,effect,Flip\n\
+            4,Ritual Bridge,LIGHT,3,Fiend,100,200,ritual,\n",
+        )
+        .unwrap();
+        let mut bridges = searcher
+            .find_universal_bridges(&[1])
+            .monsters()
+            .iter()
+            .map(|m| m.id())
+            .collect::<Vec<_>>();
+        bridges.sort();
+        assert_eq!(bridges, [2, 3, 4]);
+
+        let filter = |query: &str| {
+            searcher
+                .filter_ids(&CardFilter::parse(query).unwrap(), &bridges)
+                .ids()
+        };
+        assert_eq!(filter("frame:normal"), [2]);
+        assert_eq!(filter("-frame:ritual"), [2, 3]);
+        assert_eq!(filter("ability:flip"), [3]);
+    }
+
+    #[test]
+    fn test_search_names() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        assert_eq!(searcher.search_names("blue-eyes", 1), vec![89631139]);
+        assert_eq!(searcher.search_names("ash blosom", 1), vec![14558127]);
+        assert_eq!(searcher.resolve_name("7 colored fish"), Some(23771716));
+        assert_eq!(searcher.resolve_name("Maxx \"C\""), None);
+    }
+
+    #[test]
+    fn test_aliases() {
+        let mut searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let lacooda_alt = 86988865;
+        let fish = 23771716;
+        assert_eq!(searcher.canonical_id(lacooda_alt), None);
+
+        searcher.set_aliases(&AliasTable::try_from_csv("alias,id\n86988865,86988864\n").unwrap());
+        assert_eq!(searcher.canonical_id(lacooda_alt), Some(86988864));
+        assert_eq!(searcher.get_by_id(lacooda_alt).unwrap().id(), 86988864);
+        assert_eq!(
+            searcher
+                .find_common_bridges(&[lacooda_alt], &[fish])
+                .monsters()[0]
+                .id(),
+            63198739
+        );
+
+        let mut deck = Deck::new();
+        deck.add(Section::Main, SMALL_WORLD_ID, 3);
+        deck.add(Section::Main, 86988864, 2);
+        deck.add(Section::Main, lacooda_alt, 1);
+        deck.add(Section::Main, 63198739, 1);
+        deck.add(Section::Main, fish, 1);
+        deck.add(Section::Main, 1, 32);
+        let odds = searcher.opening_odds(&deck, HAND_SIZE_FIRST, &[fish]);
+        let mut canonical = Deck::new();
+        for &(id, copies) in deck.entries(Section::Main) {
+            canonical.add(Section::Main, searcher.aliases().canonical(id), copies);
+        }
+        let expected = searcher.opening_odds(&canonical, HAND_SIZE_FIRST, &[fish]);
+        assert_eq!(odds.per_target(), expected.per_target());
+
+        let lines = searcher.find_hand_lines(&[SMALL_WORLD_ID, lacooda_alt], &deck);
+        assert!(lines.lines().iter().all(|l| l.hand() == 86988864));
+    }
+
+    #[test]
+    fn test_other_cards_from_database() {
+        let data = "# schema 4\n\
+            id,name,attribute,level,type,atk,def,frame\n\
+            14558127,Ash Blossom & Joyous Spring,FIRE,3,Zombie,0,1800,effect\n\
+            44052075,Extra Deck Monster,DARK,,,,,fusion\n";
+        let searcher = SmallWorldSearcher::try_from_csv(data).unwrap();
+        let diagnostics = searcher.diagnose(&[14558127, 44052075, 1]);
+        let reasons = diagnostics
+            .ignored()
+            .iter()
+            .map(|e| (e.id(), e.reason()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reasons,
+            vec![
+                (44052075, IgnoreReason::NotMainDeckMonster),
+                (1, IgnoreReason::NotInDatabase),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let mut searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        searcher.set_aliases(&AliasTable::try_from_csv("alias,id\n86988865,86988864\n").unwrap());
+        let token = 44052075; // an Extra Deck monster
+        searcher.set_other_cards(&[token]);
+
+        let pool = [86988865, 23771716, token, 1, SMALL_WORLD_ID, 1];
+        let diagnostics = searcher.compute_links_within(&pool).diagnostics();
+        let reasons = diagnostics
+            .ignored()
+            .iter()
+            .map(|e| (e.id(), e.reason(), e.canonical()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reasons,
+            vec![
+                (86988865, IgnoreReason::AliasResolved, Some(86988864)),
+                (token, IgnoreReason::NotMainDeckMonster, None),
+                (1, IgnoreReason::NotInDatabase, None),
+                (SMALL_WORLD_ID, IgnoreReason::NotMainDeckMonster, None),
+            ]
+        );
+        assert_eq!(diagnostics.dropped_ids(), vec![token, 1, SMALL_WORLD_ID]);
+        assert_eq!(searcher.diagnose(&pool), diagnostics);
+
+        // every result carries the diagnostics of its own query, Small World is expected in decks
+        let bridges = searcher.find_universal_bridges(&[23771716]);
+        assert!(bridges.diagnostics().is_empty());
+        let deck = Deck::from_ids(&[SMALL_WORLD_ID, 23771716, 2], &[], &[]);
+        let odds = searcher.opening_odds(&deck, HAND_SIZE_FIRST, &[23771716]);
+        assert_eq!(odds.diagnostics().dropped_ids(), vec![2]);
+        let bridges = searcher.find_common_bridges(&[1], &[23771716]);
+        assert!(bridges.monsters().is_empty());
+        assert_eq!(bridges.diagnostics().dropped_ids(), vec![1]);
+    }
+
+    #[test]
+    fn test_compute_links_within() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
         let pool = [
             14558127, 89631139, 33854624, 97268402, 17947697, 54332792, 27204311, 63198739, 8240199,
         ];
-        let links = searcher.compute_links_within(&pool);
-        for link in links {
+        let links = searcher.compute_links_within(&pool).links();
+        for link in &links {
             println!(
                 "{} --({})--> {}",
                 link.start().name(),
@@ -252,14 +1736,628 @@ mod tests {
                 link.target().name(),
             );
         }
+
+        // the links of each start monster follow the order of the pool
+        let starts = |links: &[Link]| {
+            let mut starts = links.iter().map(|l| l.start().id()).collect::<Vec<_>>();
+            starts.dedup();
+            starts
+        };
+        let mut reversed = pool;
+        reversed.reverse();
+        let mut expected = starts(&links);
+        expected.reverse();
+        assert_eq!(
+            starts(&searcher.compute_links_within(&reversed).links()),
+            expected
+        );
     }
 
     #[test]
     fn test_compute_connecting_property() {
-        let searcher = SmallWorldSearcher::from_csv(include_str!("../testing_data.csv"));
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
         let m1 = 55697723; // Surfacing Big Jaws
         let m2 = 7150545; // Buzzsaw Shark
         let property = searcher.compute_connecting_property(m1, m2);
         assert_eq!(property, Some("Attribute: WATER".into()));
+
+        let searcher = SmallWorldSearcher::try_from_csv(
+            "id,name,attribute,level,type,atk,def\n\
+            1,Warrior A,EARTH,4,Beast-Warrior,1000,1000\n\
+            2,Warrior B,FIRE,3,Beast-Warrior,1200,800\n",
+        )
+        .unwrap();
+        let property = searcher.compute_connecting_property(1, 2);
+        assert_eq!(property, Some("Type: BeastWarrior".into()));
+    }
+
+    #[test]
+    fn test_explain_path() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let hand = 86988864; // 3-Hump Lacooda
+        let bridge = 63198739; // Primite Dragon Ether Beryl
+        let target = 23771716; // 7 Colored Fish
+
+        let path = searcher.explain_path(hand, bridge, target).unwrap();
+        assert_eq!(
+            path.hand_to_bridge().connecting_properties(),
+            &[ConnectingProperty::Attribute(Attribute::EARTH)]
+        );
+        assert_eq!(path.bridge_to_target().properties(), vec![Property::Level]);
+        assert_eq!(path.bridge_to_target().values(), vec!["4"]);
+
+        // 3-Hump Lacooda does not connect to 7 Colored Fish directly
+        assert!(searcher.explain_path(hand, target, bridge).is_none());
+    }
+
+    #[test]
+    fn test_find_paths() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let hands = [86988864, 89631139]; // 3-Hump Lacooda, Blue-Eyes White Dragon
+        let targets = [23771716, 14558127]; // 7 Colored Fish, Ash Blossom & Joyous Spring
+
+        let groups = searcher.find_paths(&hands, &targets).groups();
+
+        for group in &groups {
+            let bridges = searcher
+                .find_common_bridges(&[group.hand()], &[group.target()])
+                .monsters();
+            assert_eq!(group.paths().len(), bridges.len());
+            for path in group.paths() {
+                assert!(
+                    searcher
+                        .explain_path(path.hand(), path.bridge(), path.target())
+                        .is_some()
+                );
+            }
+        }
+
+        let lacooda_to_fish = groups
+            .iter()
+            .find(|g| g.hand() == 86988864 && g.target() == 23771716)
+            .unwrap();
+        assert_eq!(lacooda_to_fish.paths().len(), 1);
+        assert_eq!(lacooda_to_fish.paths()[0].bridge(), 63198739);
+    }
+
+    #[test]
+    fn test_find_sources() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let target = 23771716; // 7 Colored Fish
+
+        let sources = searcher.find_sources(target, None).sources();
+        assert!(sources.iter().all(|s| s.source() != target));
+        for source in &sources {
+            for bridge in source.bridges() {
+                assert!(
+                    searcher
+                        .explain_path(source.source(), bridge, target)
+                        .is_some()
+                );
+            }
+        }
+        let lacooda = sources.iter().find(|s| s.source() == 86988864).unwrap();
+        assert_eq!(lacooda.bridges(), vec![63198739]);
+
+        // without Primite Dragon Ether Beryl in the pool, 3-Hump Lacooda can not reach the target
+        let pool = vec![86988864, 23771716, 14558127, 89631139];
+        let sources = searcher.find_sources(target, Some(pool)).sources();
+        assert!(sources.iter().all(|s| s.source() != 86988864));
+    }
+
+    /// Counts the unordered pairs of the deck that are connected by at least one of the bridges
+    /// but not by a bridge inside the deck.
+    fn count_new_pairs(searcher: &SmallWorldSearcher, deck: &[u32], bridges: &[u32]) -> u32 {
+        let connected = |h: u32, t: u32, pool: &[u32]| {
+            pool.iter()
+                .any(|&b| searcher.explain_path(h, b, t).is_some())
+        };
+        let mut count = 0;
+        for (i, &h) in deck.iter().enumerate() {
+            for &t in &deck[i + 1..] {
+                if !connected(h, t, deck) && connected(h, t, bridges) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn test_recommend_bridges() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let deck = [
+            14558127, 89631139, 33854624, 97268402, 17947697, 54332792, 27204311, 63198739, 8240199,
+        ];
+
+        let recommendations = searcher.recommend_bridges(&deck, 100).recommendations();
+        assert!(!recommendations.is_empty());
+        assert!(
+            recommendations
+                .windows(2)
+                .all(|w| w[0].new_connections() >= w[1].new_connections())
+        );
+        for r in &recommendations {
+            assert!(!deck.contains(&r.bridge()));
+            assert_eq!(
+                r.new_connections(),
+                count_new_pairs(&searcher, &deck, &[r.bridge()])
+            );
+        }
+
+        assert_eq!(
+            searcher.recommend_bridges(&deck, 1).recommendations().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_select_bridges() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let deck = [
+            14558127, 89631139, 33854624, 97268402, 17947697, 54332792, 27204311, 63198739, 8240199,
+        ];
+
+        let selected = searcher.select_bridges(&deck, 3).recommendations();
+        let best = searcher.recommend_bridges(&deck, 1).recommendations();
+        assert_eq!(selected[0].bridge(), best[0].bridge());
+
+        let bridges = selected.iter().map(|r| r.bridge()).collect::<Vec<_>>();
+        let total = selected.iter().map(|r| r.new_connections()).sum::<u32>();
+        assert_eq!(total, count_new_pairs(&searcher, &deck, &bridges));
+    }
+
+    #[test]
+    fn test_find_chains() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let hand = 86988864; // 3-Hump Lacooda
+        let pool = [
+            23771716, 14558127, 89631139, 33854624, 97268402, 54332792, 27204311, 63198739, 8240199,
+        ];
+
+        let single = searcher.find_chains(hand, &pool, 1).chains();
+        let reachable = searcher
+            .compute_links_within(&[&pool[..], &[hand]].concat())
+            .links()
+            .into_iter()
+            .filter(|l| l.start().id() == hand)
+            .map(|l| l.target().id())
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(single.len(), reachable.len());
+
+        let chains = searcher.find_chains(hand, &pool, 3).chains();
+        assert!(chains.len() >= single.len());
+        assert!(
+            chains
+                .windows(2)
+                .all(|w| w[0].activations() <= w[1].activations())
+        );
+        for chain in &chains {
+            let path = chain.path();
+            assert_eq!(path.len() as u32, 2 * chain.activations() + 1);
+            assert_eq!(path[0], hand);
+            assert_eq!(*path.last().unwrap(), chain.target());
+            for hop in path.windows(3).step_by(2) {
+                assert!(searcher.explain_path(hop[0], hop[1], hop[2]).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn test_connection_rule() {
+        let mut searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let hand = 86988864; // 3-Hump Lacooda
+        let target = 23771716; // 7 Colored Fish
+        let blue_eyes = 89631139;
+        let ash = 14558127;
+        let default_bridges = searcher
+            .find_common_bridges(&[blue_eyes], &[ash])
+            .monsters();
+
+        searcher.set_connection_rule(&ConnectionRule::at_least(Property::ALL.to_vec(), 1));
+        let bridges = searcher
+            .find_common_bridges(&[blue_eyes], &[ash])
+            .monsters();
+        assert!(bridges.len() > default_bridges.len());
+        assert!(default_bridges.iter().all(|b| bridges.contains(b)));
+        for bridge in bridges {
+            let path = searcher.explain_path(blue_eyes, bridge.id(), ash).unwrap();
+            assert!(!path.hand_to_bridge().connecting_properties().is_empty());
+        }
+
+        // Primite Dragon Ether Beryl connects via attribute and level, ignoring both leaves no bridge
+        searcher.set_connection_rule(&ConnectionRule::exactly(
+            vec![Property::Type, Property::Atk, Property::Def],
+            1,
+        ));
+        assert!(searcher.explain_path(hand, 63198739, target).is_none());
+
+        searcher.set_connection_rule(&ConnectionRule::small_world());
+        assert_eq!(
+            searcher
+                .find_common_bridges(&[hand], &[target])
+                .monsters()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_unknown_values() {
+        let searcher = SmallWorldSearcher::try_from_csv(
+            "id,name,attribute,level,type,atk,def\n\
+            10000040,Holactie the Creator of Light,DIVINE,12,Creator God,-1,-1\n\
+            1,New Monster,LAUGH,4,Creator God,1000,1000\n\
+            2,Other New Monster,LAUGH,4,Dragon,1000,2000\n\
+            89631139,Blue-Eyes White Dragon,LIGHT,8,Dragon,3000,2500\n",
+        )
+        .unwrap();
+        assert_eq!(searcher.load_warnings().len(), 4);
+        assert_eq!(
+            searcher
+                .index
+                .by_type
+                .get(&Type::Unknown("Creator God".to_string()))
+                .unwrap()
+                .count_ones(),
+            2
+        );
+        assert_eq!(searcher.connected(10000040, 1), Some(true));
+        assert_eq!(searcher.connected(10000040, 89631139), Some(false));
+        assert_eq!(
+            searcher.compute_connecting_property(1, 10000040),
+            Some("Type: Creator God".to_string())
+        );
+        // attribute, level and ATK in common
+        assert_eq!(searcher.connected(1, 2), Some(false));
+
+        // unknown values of the database can still be filtered, typos are errors
+        let ids = |query: &str| {
+            let mut ids = searcher
+                .search(&searcher.parse_filter(query).unwrap())
+                .iter()
+                .map(|m| m.id())
+                .collect::<Vec<_>>();
+            ids.sort();
+            ids
+        };
+        assert_eq!(ids("type:\"creator god\""), vec![1, 10000040]);
+        assert_eq!(ids("attr:laugh -type:dragon"), vec![1]);
+        assert!(CardFilter::parse("type:\"creator god\"").is_err());
+        let error = searcher.parse_filter("type:Feind").unwrap_err();
+        assert_eq!(
+            (error.position(), error.message()),
+            (5, "unknown value 'Feind'".to_string())
+        );
+        assert_eq!(
+            searcher
+                .parse_filter("attr:DARK,PINK")
+                .unwrap_err()
+                .position(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_from_csv_lenient() {
+        let data = "id,name,attribute,level,type,atk,def\n\
+            86988864,3-Hump Lacooda,EARTH,3,Beast,500,1500\n\
+            23771716,7 Colored Fish,WATER,four,Fish,1800,800\n\
+            63198739,Primite Dragon Ether Beryl,EARTH,4,Dragon,1600,0\n";
+        let error = SmallWorldSearcher::try_from_csv(data).err().unwrap();
+        assert_eq!((error.row(), error.column()), (3, "level"));
+
+        let searcher = SmallWorldSearcher::try_from_csv_lenient(data).unwrap();
+        assert_eq!(searcher.monsters.len(), 2);
+        assert_eq!(searcher.canonical_id(23771716), None);
+        let warnings = searcher.load_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].skipped());
+        assert_eq!(warnings[0].value(), "four");
+    }
+
+    #[test]
+    fn test_unknown_stats() {
+        let mut searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let gren = 36584821; // Gren Maju Da Eiza, ?/?
+        let ra = 10000080; // The Winged Dragon of Ra - Sphere Mode, ?/?
+        let veiler = 97268402; // Effect Veiler, 0/0
+        searcher.set_connection_rule(&ConnectionRule::exactly(vec![Property::Atk], 1));
+        assert_eq!(searcher.unknown_stats(), UnknownStats::MatchEachOther);
+
+        for (unknown, gren_ra, ra_veiler, property) in [
+            (UnknownStats::MatchEachOther, true, false, Some("ATK: ?")),
+            (UnknownStats::NeverMatch, false, false, None),
+            (UnknownStats::AsZero, true, true, Some("ATK: 0")),
+        ] {
+            searcher.set_unknown_stats(unknown);
+            assert_eq!(searcher.connected(gren, ra), Some(gren_ra));
+            assert_eq!(searcher.connected(ra, veiler), Some(ra_veiler));
+            assert_eq!(
+                searcher.compute_connecting_property(gren, ra),
+                property.map(String::from)
+            );
+
+            searcher.precompute_adjacency();
+            assert_eq!(searcher.connected(gren, ra), Some(gren_ra));
+            assert_eq!(searcher.connected(ra, veiler), Some(ra_veiler));
+        }
+    }
+
+    #[test]
+    fn test_precompute_adjacency() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let pool = [
+            14558127, 89631139, 33854624, 97268402, 17947697, 54332792, 27204311, 63198739, 8240199,
+        ];
+        let links = searcher.compute_links_within(&pool).links();
+        let bridges = searcher
+            .find_universal_bridges(&[86988864, 23771716])
+            .monsters();
+
+        searcher.precompute_adjacency();
+        assert!(searcher.is_adjacency_precomputed());
+        for (i, monster) in searcher.monsters.iter().enumerate() {
+            let expected = find_neighborhood_bitset(monster, &searcher.index, &searcher.rule);
+            assert_eq!(searcher.neighborhood(i).ones(), expected.ones());
+        }
+
+        let cached_links = searcher.compute_links_within(&pool).links();
+        assert_eq!(links.len(), cached_links.len());
+        assert_eq!(
+            searcher
+                .find_universal_bridges(&[86988864, 23771716])
+                .monsters(),
+            bridges
+        );
+
+        let mut searcher = searcher;
+        searcher.set_connection_rule(&ConnectionRule::at_least(Property::ALL.to_vec(), 1));
+        assert!(!searcher.is_adjacency_precomputed());
+    }
+
+    #[test]
+    fn test_compute_link_groups_within() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let pool = [
+            14558127, 89631139, 33854624, 97268402, 17947697, 54332792, 27204311, 63198739, 8240199,
+        ];
+
+        let mut expected: HashMap<(u32, u32), Vec<u32>> = HashMap::new();
+        for link in searcher.compute_links_within(&pool).links() {
+            let (s, t) = (link.start().id(), link.target().id());
+            let key = if expected.contains_key(&(t, s)) {
+                (t, s)
+            } else {
+                (s, t)
+            };
+            let bridges = expected.entry(key).or_default();
+            if !bridges.contains(&link.bridge().id()) {
+                bridges.push(link.bridge().id());
+            }
+        }
+
+        let groups = searcher.compute_link_groups_within(&pool);
+        assert_eq!(groups.len(), expected.len());
+        assert_eq!(
+            *groups.bridge_offsets().last().unwrap() as usize,
+            groups.bridges().len()
+        );
+        for (source, target, bridges) in groups.iter() {
+            let mut expected_bridges = expected
+                .get(&(source, target))
+                .or_else(|| expected.get(&(target, source)))
+                .unwrap()
+                .clone();
+            let mut bridges = bridges.to_vec();
+            expected_bridges.sort();
+            bridges.sort();
+            assert_eq!(bridges, expected_bridges);
+        }
+    }
+
+    #[test]
+    fn test_opening_odds() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let hand = 86988864; // 3-Hump Lacooda
+        let bridge = 63198739; // Primite Dragon Ether Beryl
+        let target = 23771716; // 7 Colored Fish
+        let blank = 1; // not a monster
+
+        let mut deck = Deck::new();
+        deck.add(Section::Main, SMALL_WORLD_ID, 3);
+        deck.add(Section::Main, hand, 3);
+        deck.add(Section::Main, bridge, 1);
+        deck.add(Section::Main, target, 1);
+        deck.add(Section::Main, blank, 32);
+
+        let odds = searcher.opening_odds(&deck, HAND_SIZE_FIRST, &[target]);
+        let small_world = 1.0 - binomial(37, 5) / binomial(40, 5);
+        assert!((odds.small_world() - small_world).abs() < 1e-12);
+
+        // Small World and Lacooda, while neither bridge nor target are drawn
+        let mut expected = 0.0;
+        for sw in 1..=3 {
+            for lacooda in 1..=3 {
+                if sw + lacooda <= 5 {
+                    expected +=
+                        binomial(3, sw) * binomial(3, lacooda) * binomial(32, 5 - sw - lacooda);
+                }
+            }
+        }
+        expected /= binomial(40, 5);
+        assert!((odds.per_target()[0] - expected).abs() < 1e-12);
+        assert_eq!(odds.any_target(), odds.per_target()[0]);
+
+        let going_second = searcher.opening_odds(&deck, HAND_SIZE_SECOND, &[target]);
+        assert!(going_second.per_target()[0] > odds.per_target()[0]);
+    }
+
+    #[test]
+    fn test_simulate_openings() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let mut deck = Deck::new();
+        deck.add(Section::Main, SMALL_WORLD_ID, 3);
+        for id in [86988864, 63198739, 23771716, 14558127, 89631139, 97268402] {
+            deck.add(Section::Main, id, 2);
+        }
+        deck.add(Section::Main, 1, 25);
+        let targets = [23771716, 89631139];
+
+        let exact = searcher.opening_odds(&deck, HAND_SIZE_FIRST, &targets);
+        let simulated = searcher.simulate_openings(&deck, HAND_SIZE_FIRST, &targets, 200_000, 7);
+
+        let rates = simulated.rates();
+        let (lower, upper) = (simulated.lower_bounds(), simulated.upper_bounds());
+        for (i, p) in exact.per_target().iter().enumerate() {
+            assert!(
+                lower[i] <= *p && *p <= upper[i],
+                "{} not in [{}, {}]",
+                p,
+                lower[i],
+                upper[i]
+            );
+            assert!(lower[i] <= rates[i] && rates[i] <= upper[i]);
+        }
+        let (_, any_lower, any_upper) = simulated.estimate(simulated.any_target());
+        assert!(any_lower <= exact.any_target() && exact.any_target() <= any_upper);
+
+        let again = searcher.simulate_openings(&deck, HAND_SIZE_FIRST, &targets, 200_000, 7);
+        assert_eq!(again.successes(), simulated.successes());
+    }
+
+    #[test]
+    fn test_optimize_deck() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let hand = 86988864; // 3-Hump Lacooda
+        let bridge = 63198739; // Primite Dragon Ether Beryl
+        let target = 23771716; // 7 Colored Fish
+
+        let mut core = Deck::new();
+        core.add(Section::Main, SMALL_WORLD_ID, 3);
+        core.add(Section::Main, hand, 3);
+        core.add(Section::Main, target, 1);
+        core.add(Section::Main, 1, 33);
+        let candidates = [14558127, 89631139, bridge, 2];
+
+        let mut config = OptimizerConfig::new(2, HAND_SIZE_FIRST);
+        let greedy = searcher.optimize_deck(&core, &candidates, &[target], &config);
+        assert_eq!(greedy.deck().count(Section::Main, bridge), 1);
+        assert_eq!(greedy.deck().len(Section::Main), 42);
+        assert!(greedy.coverage() > 0.0);
+        let expected = searcher.opening_odds(&greedy.deck(), HAND_SIZE_FIRST, &[target]);
+        assert!((greedy.coverage() - expected.per_target()[0]).abs() < 1e-12);
+
+        config.strategy = OptimizerStrategy::LocalSearch;
+        config.seed = 3;
+        let local = searcher.optimize_deck(&core, &candidates, &[target], &config);
+        assert!(local.coverage() >= greedy.coverage());
+        assert!(local.deck().len(Section::Main) <= 42);
+        let again = searcher.optimize_deck(&core, &candidates, &[target], &config);
+        assert_eq!(again.deck(), local.deck());
+
+        // the local search never adds more cards than the budget
+        for budget in 0..4 {
+            config.budget = budget;
+            let local = searcher.optimize_deck(&core, &candidates, &[target], &config);
+            assert!(local.deck().len(Section::Main) <= 40 + budget);
+        }
+    }
+
+    #[test]
+    fn test_monster_limit() {
+        let mut csv = "id,name,attribute,level,type,atk,def\n".to_string();
+        for id in 1..=130 {
+            csv += &format!("{id},Monster {id},DARK,{},Fiend,{id},0\n", id % 12 + 1);
+        }
+        let searcher = SmallWorldSearcher::try_from_csv(&csv).unwrap();
+        let ids = (1..=129).collect::<Vec<_>>();
+        let deck = Deck::from_ids(&ids, &[], &[]);
+
+        // monsters past the limit are reported instead of silently left out
+        let odds = searcher.opening_odds(&deck, HAND_SIZE_FIRST, &[1]);
+        assert_eq!(odds.diagnostics().dropped_ids(), vec![129]);
+        let config = OptimizerConfig::new(1, HAND_SIZE_FIRST);
+        let optimized = searcher.optimize_deck(&deck, &[130], &[1], &config);
+        assert_eq!(optimized.diagnostics().dropped_ids(), vec![129, 130]);
+    }
+
+    #[test]
+    fn test_find_hand_lines() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let lacooda = 86988864;
+        let primite = 63198739;
+        let fish = 23771716;
+
+        let mut deck = Deck::new();
+        deck.add(Section::Main, lacooda, 2);
+        deck.add(Section::Main, primite, 1);
+        deck.add(Section::Main, fish, 2);
+
+        let hand = [SMALL_WORLD_ID, lacooda, lacooda];
+        let result = searcher.find_hand_lines(&hand, &deck);
+        assert!(result.has_small_world());
+        let lines = result.lines();
+        // the duplicate Lacooda in hand is only revealed once
+        assert_eq!(
+            lines
+                .iter()
+                .filter(|l| l.bridge() == primite && l.target() == fish)
+                .count(),
+            1
+        );
+        for line in &lines {
+            assert_eq!(line.hand(), lacooda);
+            assert!(deck.count(Section::Main, line.bridge()) > 0);
+            assert!(deck.count(Section::Main, line.target()) > 0);
+        }
+
+        // once the only Primite Dragon Ether Beryl is drawn, it can no longer be the bridge
+        deck.remove(Section::Main, primite, 1);
+        let hand = [SMALL_WORLD_ID, lacooda, primite];
+        let lines = searcher.find_hand_lines(&hand, &deck).lines();
+        assert!(lines.iter().all(|l| l.bridge() != primite));
+    }
+
+    #[test]
+    fn test_compute_deck_stats() {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let lacooda = 86988864;
+        let primite = 63198739;
+        let fish = 23771716;
+        let ra = 10000080; // The Winged Dragon of Ra - Sphere Mode
+        let pool = [lacooda, primite, fish, ra];
+
+        let stats = searcher.compute_deck_stats(&pool);
+        let degree = |id| stats.degrees()[stats.ids().iter().position(|&i| i == id).unwrap()];
+        assert_eq!(degree(lacooda), 1);
+        assert_eq!(degree(fish), 1);
+        assert_eq!(degree(ra), 0);
+
+        // Lacooda and the fish share a component, Primite and Ra are on their own
+        assert_eq!(stats.component_count(), 3);
+        assert_eq!(stats.bridges(), vec![primite]);
+        assert_eq!(stats.bridge_share(), vec![1.0]);
+        assert_eq!(stats.critical_bridges(), vec![primite]);
+        assert_eq!(stats.critical_pairs(), vec![1]);
+        assert!(stats.unreachable().contains(&ra));
+        assert!(stats.dead_ends().contains(&primite));
+        assert!(!stats.dead_ends().contains(&lacooda));
     }
 }
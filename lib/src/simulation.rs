@@ -0,0 +1,233 @@
+use crate::diagnostics::Diagnostics;
+use crate::probability::DeckGraph;
+use wasm_bindgen::prelude::*;
+
+/// z-score of the 95% confidence intervals
+const Z_95: f64 = 1.959964;
+
+/// SplitMix64, small and fast enough to shuffle millions of decks reproducibly.
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// uniform number in 0..n
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+}
+
+/// Success rates of simulated opening hands, with 95% confidence intervals.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct SimulationResult {
+    trials: u32,
+    small_world: u32,
+    targets: Vec<u32>,
+    per_target: Vec<u32>,
+    any_target: u32,
+    diagnostics: Diagnostics,
+}
+
+impl SimulationResult {
+    pub(crate) fn with_diagnostics(self, diagnostics: Diagnostics) -> Self {
+        SimulationResult {
+            diagnostics,
+            ..self
+        }
+    }
+
+    /// (rate, lower bound, upper bound) of the Wilson score interval for the number of successes.
+    pub fn estimate(&self, successes: u32) -> (f64, f64, f64) {
+        wilson_interval(successes, self.trials)
+    }
+}
+
+#[wasm_bindgen]
+impl SimulationResult {
+    #[wasm_bindgen(getter)]
+    pub fn trials(&self) -> u32 {
+        self.trials
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn targets(&self) -> Vec<u32> {
+        self.targets.clone()
+    }
+
+    /// number of hands that reached each target, in the order of `targets`
+    #[wasm_bindgen(getter)]
+    pub fn successes(&self) -> Vec<u32> {
+        self.per_target.clone()
+    }
+
+    /// success rate of each target
+    #[wasm_bindgen(getter)]
+    pub fn rates(&self) -> Vec<f64> {
+        self.per_target
+            .iter()
+            .map(|&s| self.estimate(s).0)
+            .collect()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn lower_bounds(&self) -> Vec<f64> {
+        self.per_target
+            .iter()
+            .map(|&s| self.estimate(s).1)
+            .collect()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn upper_bounds(&self) -> Vec<f64> {
+        self.per_target
+            .iter()
+            .map(|&s| self.estimate(s).2)
+            .collect()
+    }
+
+    /// number of hands that opened Small World
+    #[wasm_bindgen(getter)]
+    pub fn small_world(&self) -> u32 {
+        self.small_world
+    }
+
+    /// number of hands that reached at least one target
+    #[wasm_bindgen(getter)]
+    pub fn any_target(&self) -> u32 {
+        self.any_target
+    }
+
+    /// passcodes of the query that were ignored or resolved as alias
+    #[wasm_bindgen(getter)]
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.clone()
+    }
+}
+
+/// Shuffles the deck `trials` times and checks which targets the top `hand_size` cards can reach.
+///
+/// Every hand is evaluated against the cards left in the deck, so drawing the only
+/// copy of a bridge or target removes that line. The same seed gives the same result.
+pub(crate) fn simulate(
+    graph: &DeckGraph,
+    hand_size: u32,
+    targets: &[u32],
+    trials: u32,
+    seed: u64,
+) -> SimulationResult {
+    const SMALL_WORLD: usize = usize::MAX;
+    const OTHER: usize = usize::MAX - 1;
+
+    let target_positions = targets
+        .iter()
+        .map(|&id| graph.position(id))
+        .collect::<Vec<_>>();
+
+    let mut cards = vec![];
+    for (i, &copies) in graph.copies().iter().enumerate() {
+        cards.extend(std::iter::repeat_n(i, copies as usize));
+    }
+    cards.extend(std::iter::repeat_n(
+        SMALL_WORLD,
+        graph.small_world() as usize,
+    ));
+    cards.extend(std::iter::repeat_n(OTHER, graph.other() as usize));
+    let hand_size = (hand_size as usize).min(cards.len());
+
+    let mut rng = Rng::new(seed);
+    let mut result = SimulationResult {
+        trials,
+        small_world: 0,
+        targets: targets.to_vec(),
+        per_target: vec![0; targets.len()],
+        any_target: 0,
+        diagnostics: Diagnostics::default(),
+    };
+    let mut drawn = vec![0; graph.copies().len()];
+
+    for _ in 0..trials {
+        // partial Fisher-Yates, the first hand_size cards are a uniform random hand
+        for i in 0..hand_size {
+            let j = i + rng.below(cards.len() - i);
+            cards.swap(i, j);
+        }
+
+        drawn.iter_mut().for_each(|d| *d = 0);
+        let mut small_world = false;
+        for &card in &cards[..hand_size] {
+            match card {
+                SMALL_WORLD => small_world = true,
+                OTHER => {}
+                monster => drawn[monster] += 1,
+            }
+        }
+        if !small_world {
+            continue;
+        }
+        result.small_world += 1;
+
+        let (hand, deck) = graph.hand_and_deck(&drawn);
+        let mut any = false;
+        for (successes, target) in result.per_target.iter_mut().zip(&target_positions) {
+            if target.is_some_and(|t| graph.reaches(t, hand, deck)) {
+                *successes += 1;
+                any = true;
+            }
+        }
+        if any {
+            result.any_target += 1;
+        }
+    }
+
+    result
+}
+
+/// Wilson score interval, returns (rate, lower bound, upper bound).
+fn wilson_interval(successes: u32, trials: u32) -> (f64, f64, f64) {
+    if trials == 0 {
+        return (0.0, 0.0, 1.0);
+    }
+    let n = trials as f64;
+    let p = successes as f64 / n;
+    let z2 = Z_95 * Z_95;
+    let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+    let margin = Z_95 / (1.0 + z2 / n) * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt();
+    (p, (center - margin).max(0.0), (center + margin).min(1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_range() {
+        let mut rng = Rng::new(42);
+        let mut seen = [0u32; 6];
+        for _ in 0..6000 {
+            seen[rng.below(6)] += 1;
+        }
+        assert!(seen.iter().all(|&s| s > 900 && s < 1100));
+    }
+
+    #[test]
+    fn test_wilson_interval() {
+        let (p, lower, upper) = wilson_interval(50, 100);
+        assert_eq!(p, 0.5);
+        assert!((lower - 0.4038).abs() < 1e-3);
+        assert!((upper - 0.5962).abs() < 1e-3);
+        assert_eq!(wilson_interval(0, 10).1, 0.0);
+    }
+}
@@ -0,0 +1,277 @@
+use crate::alias::AliasTable;
+use crate::deck::{Deck, Section};
+use crate::probability::SMALL_WORLD_ID;
+use crate::searcher::{HandLines, SmallWorldSearcher};
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// Why a Small World activation can not be resolved.
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ActivationError {
+    NoSmallWorld,
+    RevealNotInHand,
+    BridgeNotInDeck,
+    TargetNotInDeck,
+    NotAMonster,
+    BridgeNotConnected,
+    TargetNotConnected,
+}
+
+impl fmt::Display for ActivationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            ActivationError::NoSmallWorld => "Small World is not in the hand",
+            ActivationError::RevealNotInHand => "the revealed monster is not in the hand",
+            ActivationError::BridgeNotInDeck => "the bridge is not in the deck",
+            ActivationError::TargetNotInDeck => "the target is not in the deck",
+            ActivationError::NotAMonster => "the card is not a main deck monster",
+            ActivationError::BridgeNotConnected => {
+                "the bridge is not connected to the revealed monster"
+            }
+            ActivationError::TargetNotConnected => "the target is not connected to the bridge",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl std::error::Error for ActivationError {}
+
+/// The zones touched by Small World, each card is one entry per copy.
+/// Alternate artworks are stored with the passcode of the card database.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default)]
+pub struct GameState {
+    hand: Vec<u32>,
+    deck: Deck, // the cards left in the deck, in the main section
+    banished: Vec<u32>,
+    graveyard: Vec<u32>,
+    aliases: AliasTable,
+}
+
+#[wasm_bindgen]
+impl GameState {
+    /// `deck` holds the cards left in the main deck, without the hand.
+    /// Passcodes are resolved with the alias table of the searcher.
+    #[wasm_bindgen(constructor)]
+    pub fn new(hand_ids: Vec<u32>, deck: &Deck, searcher: &SmallWorldSearcher) -> GameState {
+        let aliases = searcher.aliases();
+        let mut remaining = Deck::new();
+        for &(id, copies) in deck.entries(Section::Main) {
+            remaining.add(Section::Main, aliases.canonical(id), copies);
+        }
+        GameState {
+            hand: hand_ids.iter().map(|&id| aliases.canonical(id)).collect(),
+            deck: remaining,
+            banished: vec![],
+            graveyard: vec![],
+            aliases,
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn hand(&self) -> Vec<u32> {
+        self.hand.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn deck(&self) -> Deck {
+        self.deck.clone()
+    }
+
+    /// cards banished face-down, in the order they were banished
+    #[wasm_bindgen(getter)]
+    pub fn banished(&self) -> Vec<u32> {
+        self.banished.clone()
+    }
+
+    /// resolved copies of Small World
+    #[wasm_bindgen(getter)]
+    pub fn graveyard(&self) -> Vec<u32> {
+        self.graveyard.clone()
+    }
+
+    /// Checks whether Small World can be resolved with the given cards, without changing the zones.
+    pub fn check_small_world(
+        &self,
+        searcher: &SmallWorldSearcher,
+        reveal: u32,
+        bridge: u32,
+        target: u32,
+    ) -> Option<ActivationError> {
+        let [reveal, bridge, target] =
+            [reveal, bridge, target].map(|id| self.aliases.canonical(id));
+        if !self.hand.contains(&SMALL_WORLD_ID) {
+            return Some(ActivationError::NoSmallWorld);
+        }
+        if !self.hand.contains(&reveal) {
+            return Some(ActivationError::RevealNotInHand);
+        }
+        if self.deck.count(Section::Main, bridge) == 0 {
+            return Some(ActivationError::BridgeNotInDeck);
+        }
+        let needed = if target == bridge { 2 } else { 1 };
+        if self.deck.count(Section::Main, target) < needed {
+            return Some(ActivationError::TargetNotInDeck);
+        }
+        match (
+            searcher.connected(reveal, bridge),
+            searcher.connected(bridge, target),
+        ) {
+            (None, _) | (_, None) => Some(ActivationError::NotAMonster),
+            (Some(false), _) => Some(ActivationError::BridgeNotConnected),
+            (_, Some(false)) => Some(ActivationError::TargetNotConnected),
+            _ => None,
+        }
+    }
+
+    /// Resolves Small World: the revealed monster is banished face-down from the hand,
+    /// the target is added from the deck to the hand, then the bridge is banished face-down
+    /// from the deck. Small World itself is sent to the graveyard.
+    /// Nothing changes if the activation is not legal.
+    pub fn apply_small_world(
+        &mut self,
+        searcher: &SmallWorldSearcher,
+        reveal: u32,
+        bridge: u32,
+        target: u32,
+    ) -> Result<(), ActivationError> {
+        if let Some(error) = self.check_small_world(searcher, reveal, bridge, target) {
+            return Err(error);
+        }
+        let [reveal, bridge, target] =
+            [reveal, bridge, target].map(|id| self.aliases.canonical(id));
+
+        self.remove_from_hand(SMALL_WORLD_ID);
+        self.graveyard.push(SMALL_WORLD_ID);
+        self.remove_from_hand(reveal);
+        self.banished.push(reveal);
+        self.deck.remove(Section::Main, target, 1);
+        self.hand.push(target);
+        self.deck.remove(Section::Main, bridge, 1);
+        self.banished.push(bridge);
+        Ok(())
+    }
+
+    /// Every line that can be played from the current hand into the current deck.
+    pub fn lines(&self, searcher: &SmallWorldSearcher) -> HandLines {
+        searcher.find_hand_lines(&self.hand, &self.deck)
+    }
+
+    /// Moves a copy of the card from the deck to the hand, returns false if none is left.
+    pub fn draw(&mut self, id: u32) -> bool {
+        let id = self.aliases.canonical(id);
+        let drawn = self.deck.remove(Section::Main, id, 1) == 1;
+        if drawn {
+            self.hand.push(id);
+        }
+        drawn
+    }
+}
+
+impl GameState {
+    fn remove_from_hand(&mut self, id: u32) {
+        if let Some(position) = self.hand.iter().position(|&i| i == id) {
+            self.hand.remove(position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LACOODA: u32 = 86988864;
+    const PRIMITE: u32 = 63198739;
+    const FISH: u32 = 23771716;
+
+    fn example() -> (SmallWorldSearcher, GameState) {
+        let searcher =
+            SmallWorldSearcher::try_from_csv(include_str!("../testing_data.csv")).unwrap();
+        let mut deck = Deck::new();
+        deck.add(Section::Main, PRIMITE, 1);
+        deck.add(Section::Main, FISH, 2);
+        deck.add(Section::Main, SMALL_WORLD_ID, 1);
+        let state = GameState::new(vec![SMALL_WORLD_ID, LACOODA], &deck, &searcher);
+        (searcher, state)
+    }
+
+    #[test]
+    fn test_apply_small_world() {
+        let (searcher, mut state) = example();
+        assert_eq!(
+            state.apply_small_world(&searcher, LACOODA, FISH, PRIMITE),
+            Err(ActivationError::BridgeNotConnected)
+        );
+        assert_eq!(
+            state.apply_small_world(&searcher, FISH, PRIMITE, FISH),
+            Err(ActivationError::RevealNotInHand)
+        );
+        assert_eq!(state.hand(), vec![SMALL_WORLD_ID, LACOODA]);
+
+        state
+            .apply_small_world(&searcher, LACOODA, PRIMITE, FISH)
+            .unwrap();
+        assert_eq!(state.hand(), vec![FISH]);
+        assert_eq!(state.banished(), vec![LACOODA, PRIMITE]);
+        assert_eq!(state.graveyard(), vec![SMALL_WORLD_ID]);
+        assert_eq!(state.deck().count(Section::Main, FISH), 1);
+        assert_eq!(state.deck().count(Section::Main, PRIMITE), 0);
+    }
+
+    #[test]
+    fn test_follow_up_queries() {
+        let (searcher, mut state) = example();
+        assert!(!state.lines(&searcher).lines().is_empty());
+        state
+            .apply_small_world(&searcher, LACOODA, PRIMITE, FISH)
+            .unwrap();
+
+        // the only bridge is banished and Small World is used
+        let lines = state.lines(&searcher);
+        assert!(!lines.has_small_world());
+        assert!(lines.lines().iter().all(|l| l.bridge() != PRIMITE));
+        assert_eq!(
+            state.apply_small_world(&searcher, FISH, PRIMITE, FISH),
+            Err(ActivationError::NoSmallWorld)
+        );
+
+        // the second copy of Small World can reveal the added target, but Primite is gone
+        assert!(state.draw(SMALL_WORLD_ID));
+        assert_eq!(
+            state.apply_small_world(&searcher, FISH, PRIMITE, FISH),
+            Err(ActivationError::BridgeNotInDeck)
+        );
+    }
+
+    #[test]
+    fn test_alternate_artworks() {
+        let (mut searcher, _) = example();
+        let mut aliases = AliasTable::new();
+        aliases.insert(PRIMITE + 1, PRIMITE);
+        aliases.insert(FISH + 1, FISH);
+        searcher.set_aliases(&aliases);
+
+        let mut deck = Deck::new();
+        deck.add(Section::Main, PRIMITE + 1, 1);
+        deck.add(Section::Main, FISH, 1);
+        deck.add(Section::Main, FISH + 1, 1);
+        let mut state = GameState::new(vec![SMALL_WORLD_ID, LACOODA], &deck, &searcher);
+        assert_eq!(state.deck().count(Section::Main, FISH), 2);
+
+        // the lines name the database passcodes, which are accepted by the state
+        let line = state
+            .lines(&searcher)
+            .lines()
+            .into_iter()
+            .find(|l| l.target() == FISH)
+            .unwrap();
+        assert_eq!(line.bridge(), PRIMITE);
+        state
+            .apply_small_world(&searcher, line.hand(), line.bridge(), line.target())
+            .unwrap();
+        assert_eq!(state.hand(), vec![FISH]);
+        assert!(state.draw(FISH + 1));
+        assert_eq!(state.hand(), vec![FISH, FISH]);
+    }
+}
@@ -1,79 +1,470 @@
-use crate::monster::Monster;
+use crate::alias::AliasTable;
+use crate::deck::{Deck, Section};
+use crate::monster::{Attribute, BanStatus, Monster, MonsterDetails, Type};
 use base64::prelude::*;
-use csv::{Reader, ReaderBuilder};
+use csv::{ReaderBuilder, StringRecord};
 use std::path::Path;
+use std::{fmt, fs};
 use wasm_bindgen::prelude::*;
 
-pub fn parse_csv_file<P: AsRef<Path>>(path: P) -> Vec<Monster> {
-    let mut rdr = Reader::from_path(path).expect("Failed to open CSV file");
-    let mut monsters = vec![];
+/// A row of the card database that could not be loaded.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CsvError {
+    row: u32, // line of the CSV file, 0 if the file could not be read
+    column: String,
+    value: String,
+}
 
-    for result in rdr.records() {
-        let record = result.expect("Failed to read CSV record");
-        let id: u32 = record[0].parse().expect("Invalid id");
-        let name = &record[1];
-        let attribute = record[2].parse().expect("Invalid attribute");
-        let level = record[3].parse().expect("Invalid level");
-        let r#type = record[4].parse().expect("Invalid type");
-        let atk = record[5]
-            .parse()
-            .map(|e: i32| if e == -1 { None } else { Some(e as u32) })
-            .expect("Invalid atk");
-        let def = record[6]
-            .parse()
-            .map(|e: i32| if e == -1 { None } else { Some(e as u32) })
-            .expect("Invalid def");
-        monsters.push(Monster::new(id, name, attribute, level, r#type, atk, def));
-    }
-
-    monsters
+impl CsvError {
+    pub(crate) fn new(row: u32, column: &str, value: impl Into<String>) -> CsvError {
+        CsvError {
+            row,
+            column: column.to_string(),
+            value: value.into(),
+        }
+    }
+
+    fn missing(row: u32, column: &str) -> CsvError {
+        CsvError::new(row, "header", column)
+    }
+
+    pub(crate) fn read(error: csv::Error) -> CsvError {
+        let row = error.position().map_or(0, |p| p.line() as u32);
+        CsvError::new(row, "record", error.to_string())
+    }
+
+    pub fn row(&self) -> u32 {
+        self.row
+    }
+
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.row > 0 {
+            write!(f, "line {}: ", self.row)?;
+        }
+        match self.column.as_str() {
+            "header" => write!(f, "missing column \"{}\"", self.value),
+            column => write!(f, "invalid {} \"{}\"", column, self.value),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+/// A YDKE string that could not be decoded.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum YdkeError {
+    /// fewer than the main, extra and side sections, with the number found
+    MissingSections(usize),
+    /// a section that is no valid base64
+    InvalidSection(Section),
+}
+
+impl fmt::Display for YdkeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            YdkeError::MissingSections(found) => {
+                write!(f, "expected 3 sections separated by '!', found {}", found)
+            }
+            YdkeError::InvalidSection(section) => write!(f, "invalid {} section", section),
+        }
+    }
+}
+
+impl std::error::Error for YdkeError {}
+
+/// A value of the card database this version does not know, the monster is loaded anyway,
+/// or a row that was skipped when loading leniently.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CsvWarning {
+    row: u32, // line of the CSV file
+    column: String,
+    value: String,
+    skipped: bool,
+}
+
+impl From<CsvError> for CsvWarning {
+    fn from(error: CsvError) -> Self {
+        CsvWarning {
+            row: error.row,
+            column: error.column,
+            value: error.value,
+            skipped: true,
+        }
+    }
+}
+
+impl fmt::Display for CsvWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (problem, consequence) = match self.skipped {
+            true => ("invalid", ", the row was skipped"),
+            false => ("unknown", ""),
+        };
+        write!(
+            f,
+            "line {}: {} {} \"{}\"{}",
+            self.row, problem, self.column, self.value, consequence
+        )
+    }
+}
+
+#[wasm_bindgen]
+impl CsvWarning {
+    #[wasm_bindgen(getter)]
+    pub fn row(&self) -> u32 {
+        self.row
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn column(&self) -> String {
+        self.column.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> String {
+        self.value.clone()
+    }
+
+    /// whether the monster of the row is missing from the database
+    #[wasm_bindgen(getter)]
+    pub fn skipped(&self) -> bool {
+        self.skipped
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.to_string()
+    }
 }
 
-pub fn parse_csv(data: &str) -> Vec<Monster> {
-    let mut rdr = ReaderBuilder::new().from_reader(data.as_bytes());
-    let mut monsters = vec![];
+/// The version of the CSV schema written by `scripts/make_csv.py`, stored in a `# schema <version>`
+/// line above the header. Files without it are version 1, the seven columns `id` to `def`.
+/// Version 2 added the frame, archetype, scale, banlist and release date, version 3 the abilities,
+/// version 4 the alternate passcodes of every card and the rows of Spells, Traps and Extra Deck monsters.
+pub const SCHEMA_VERSION: u32 = 4;
+
+#[wasm_bindgen]
+/// The CSV schema version of this build, a card database with an older version is stale
+pub fn csv_schema_version() -> u32 {
+    SCHEMA_VERSION
+}
 
+/// The monsters of a card database, with everything noticed while loading it.
+#[derive(Debug, Clone, Default)]
+pub struct CsvDatabase {
+    pub monsters: Vec<Monster>,
+    pub aliases: AliasTable,
+    pub other_cards: Vec<u32>, // passcodes of the cards that are no main deck monsters
+    pub warnings: Vec<CsvWarning>,
+    pub schema_version: u32,
+}
+
+pub fn parse_csv_file<P: AsRef<Path>>(path: P) -> Result<Vec<Monster>, CsvError> {
+    let data = fs::read_to_string(path).map_err(|e| CsvError::new(0, "file", e.to_string()))?;
+    parse_csv(&data)
+}
+
+pub fn parse_csv(data: &str) -> Result<Vec<Monster>, CsvError> {
+    load_csv(data, false).map(|database| database.monsters)
+}
+
+/// Load a card database, columns are found by their header name and may come in any order.
+///
+/// Besides `id`, `name`, `attribute`, `level`, `type`, `atk` and `def` the optional columns
+/// `frame`, `abilities` (separated by spaces), `archetype`, `scale`, `banlist`, `release_date`
+/// and `aliases` (the passcodes of alternate artworks, separated by spaces) are read,
+/// any other column is ignored. Rows of Spells, Traps and Extra Deck monsters, recognized by
+/// their frame (e.g. `spell` or `fusion`), only need the `id` and are kept as other cards.
+/// Unknown attributes, types, frames, abilities and banlist statuses are reported as warnings. A row that can not
+/// be parsed fails the whole file, unless `lenient` is set: then it is skipped and reported as well.
+pub fn load_csv(data: &str, lenient: bool) -> Result<CsvDatabase, CsvError> {
+    let schema_version = schema_version(data)?;
+    let mut rdr = ReaderBuilder::new()
+        .flexible(true)
+        .comment(Some(b'#'))
+        .from_reader(data.as_bytes());
+    let columns = Columns::new(rdr.headers().map_err(CsvError::read)?)?;
+
+    let mut database = CsvDatabase {
+        schema_version,
+        ..CsvDatabase::default()
+    };
     for result in rdr.records() {
-        let record = result.expect("Failed to read CSV record");
-        let id: u32 = record[0].parse().expect("Invalid id");
-        let name = &record[1];
-        let attribute = record[2].parse().expect("Invalid attribute");
-        let level = record[3].parse().expect("Invalid level");
-        let r#type = record[4].parse().expect("Invalid type");
-        let atk = record[5]
-            .parse()
-            .map(|e: i32| if e == -1 { None } else { Some(e as u32) })
-            .expect("Invalid atk");
-        let def = record[6]
-            .parse()
-            .map(|e: i32| if e == -1 { None } else { Some(e as u32) })
-            .expect("Invalid def");
-        monsters.push(Monster::new(id, name, attribute, level, r#type, atk, def));
-    }
-
-    monsters
+        let mut unknown = vec![];
+        match result
+            .map_err(CsvError::read)
+            .and_then(|record| columns.parse(&record, &mut unknown))
+        {
+            Ok(row) => {
+                for alias in row.aliases {
+                    database.aliases.insert(alias, row.id);
+                }
+                match row.monster {
+                    Some(monster) => database.monsters.push(monster),
+                    None => database.other_cards.push(row.id),
+                }
+                database.warnings.extend(unknown);
+            }
+            Err(error) if lenient => database.warnings.push(error.into()),
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(database)
+}
+
+/// The version of the `# schema <version>` line, 1 if the file starts with the header.
+fn schema_version(data: &str) -> Result<u32, CsvError> {
+    let first = data.lines().next().unwrap_or_default().trim();
+    let Some(comment) = first.strip_prefix('#') else {
+        return Ok(1);
+    };
+    comment
+        .trim()
+        .strip_prefix("schema")
+        .and_then(|version| version.trim().parse().ok())
+        .ok_or_else(|| CsvError::new(1, "schema", first))
+}
+
+pub(crate) fn row(record: &StringRecord) -> u32 {
+    record.position().map_or(0, |p| p.line() as u32)
+}
+
+/// Frames of the cards Small World can not search, in the spelling of ygoprodeck.
+const OTHER_FRAMES: [&str; 11] = [
+    "fusion",
+    "synchro",
+    "xyz",
+    "link",
+    "fusion_pendulum",
+    "synchro_pendulum",
+    "xyz_pendulum",
+    "spell",
+    "trap",
+    "token",
+    "skill",
+];
+
+/// One row of the card database, `monster` is `None` for the other cards.
+struct Row {
+    id: u32,
+    aliases: Vec<u32>,
+    monster: Option<Monster>,
+}
+
+/// The position of every column, found by its header name.
+struct Columns {
+    id: usize,
+    name: usize,
+    attribute: usize,
+    level: usize,
+    r#type: usize,
+    atk: usize,
+    def: usize,
+    frame: Option<usize>,
+    abilities: Option<usize>,
+    archetype: Option<usize>,
+    scale: Option<usize>,
+    banlist: Option<usize>,
+    release_date: Option<usize>,
+    aliases: Option<usize>,
+}
+
+impl Columns {
+    fn new(headers: &StringRecord) -> Result<Columns, CsvError> {
+        let find = |column: &str| {
+            headers
+                .iter()
+                .position(|header| header.trim().eq_ignore_ascii_case(column))
+        };
+        let required =
+            |column: &str| find(column).ok_or_else(|| CsvError::missing(row(headers), column));
+
+        Ok(Columns {
+            id: required("id")?,
+            name: required("name")?,
+            attribute: required("attribute")?,
+            level: required("level")?,
+            r#type: required("type")?,
+            atk: required("atk")?,
+            def: required("def")?,
+            frame: find("frame"),
+            abilities: find("abilities"),
+            archetype: find("archetype"),
+            scale: find("scale"),
+            banlist: find("banlist"),
+            release_date: find("release_date"),
+            aliases: find("aliases"),
+        })
+    }
+
+    /// Parses one row, unknown values are added to `unknown`.
+    fn parse(&self, record: &StringRecord, unknown: &mut Vec<CsvWarning>) -> Result<Row, CsvError> {
+        let row = row(record);
+        let field = |i: usize, column: &str| {
+            record
+                .get(i)
+                .map(str::trim)
+                .ok_or_else(|| CsvError::new(row, column, ""))
+        };
+        // empty optional fields are treated like missing columns
+        let optional = |i: Option<usize>| {
+            i.and_then(|i| record.get(i))
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+        };
+        let number = |i: usize, column: &str| {
+            let value = field(i, column)?;
+            value
+                .parse::<u32>()
+                .map_err(|_| CsvError::new(row, column, value))
+        };
+        // -1 marks an unknown ("?") stat
+        let stat = |i: usize, column: &str| {
+            let value = field(i, column)?;
+            match value.parse::<i64>() {
+                Ok(-1) => Ok(None),
+                Ok(stat) => u32::try_from(stat)
+                    .map(Some)
+                    .map_err(|_| CsvError::new(row, column, value)),
+                Err(_) => Err(CsvError::new(row, column, value)),
+            }
+        };
+        let mut warn = |column: &str, value: &str| {
+            unknown.push(CsvWarning {
+                row,
+                column: column.to_string(),
+                value: value.to_string(),
+                skipped: false,
+            })
+        };
+
+        let id = number(self.id, "id")?;
+        let aliases = optional(self.aliases)
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| CsvError::new(row, "aliases", value))
+            })
+            .collect::<Result<Vec<u32>, _>>()?;
+        if optional(self.frame).is_some_and(|frame| OTHER_FRAMES.contains(&frame)) {
+            return Ok(Row {
+                id,
+                aliases,
+                monster: None,
+            });
+        }
+
+        let name = field(self.name, "name")?;
+        let attribute = field(self.attribute, "attribute")?;
+        let level = number(self.level, "level")?;
+        let r#type = field(self.r#type, "type")?;
+        let atk = stat(self.atk, "atk")?;
+        let def = stat(self.def, "def")?;
+        let scale = match optional(self.scale) {
+            Some(value) => Some(
+                value
+                    .parse()
+                    .map_err(|_| CsvError::new(row, "scale", value))?,
+            ),
+            None => None,
+        };
+
+        let attribute = Attribute::parse_or_unknown(attribute);
+        if attribute.is_unknown() {
+            warn("attribute", &attribute.to_string());
+        }
+        let r#type = Type::parse_or_unknown(r#type);
+        if r#type.is_unknown() {
+            warn("type", &r#type.to_string());
+        }
+        let frame = optional(self.frame).and_then(|value| {
+            let frame = value.parse().ok();
+            if frame.is_none() {
+                warn("frame", value);
+            }
+            frame
+        });
+        let abilities = optional(self.abilities)
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter_map(|value| {
+                let ability = value.parse().ok();
+                if ability.is_none() {
+                    warn("ability", value);
+                }
+                ability
+            })
+            .collect();
+        let banlist = optional(self.banlist).unwrap_or_default();
+        let ban_status = banlist.parse().unwrap_or_else(|_| {
+            warn("banlist", banlist);
+            BanStatus::default()
+        });
+
+        let details = MonsterDetails {
+            frame,
+            abilities,
+            archetype: optional(self.archetype).map(String::from),
+            scale,
+            ban_status,
+            release_date: optional(self.release_date).map(String::from),
+        };
+        let monster =
+            Monster::new(id, name, attribute, level, r#type, atk, def).with_details(details);
+        Ok(Row {
+            id,
+            aliases,
+            monster: Some(monster),
+        })
+    }
 }
 
 #[wasm_bindgen]
-pub fn parse_ydk(ydk: &str, ignore_extra: bool) -> Vec<u32> {
-    let mut ids = vec![];
-    let mut skip_extra = false;
+/// Parse a YDK file, cards are sorted into the sections by the `#main`, `#extra` and `!side` headers.
+/// With `ignore_extra` the Extra Deck is left empty.
+pub fn parse_ydk(ydk: &str, ignore_extra: bool) -> Deck {
+    let mut deck = parse_ydk_with_aliases(ydk, &AliasTable::new());
+    if ignore_extra {
+        deck.clear(Section::Extra);
+    }
+    deck
+}
+
+#[wasm_bindgen]
+/// Parse a YDK file, alternate artworks are replaced by their canonical passcode
+pub fn parse_ydk_with_aliases(ydk: &str, aliases: &AliasTable) -> Deck {
+    let mut deck = Deck::new();
+    let mut section = Section::Main;
     for line in ydk.lines() {
         let line = line.trim();
-        if ignore_extra && line.contains("extra") {
-            skip_extra = true;
-        }
-        if line.contains("main") || line.contains("side") {
-            skip_extra = false;
-        }
-        if line.starts_with('#') || line.starts_with('!') || line.is_empty() || skip_extra {
+        if line.starts_with('#') || line.starts_with('!') {
+            if line.contains("main") {
+                section = Section::Main;
+            } else if line.contains("extra") {
+                section = Section::Extra;
+            } else if line.contains("side") {
+                section = Section::Side;
+            }
             continue;
         }
         if let Ok(id) = line.parse::<u32>() {
-            ids.push(id);
+            deck.add(section, aliases.canonical(id), 1);
         }
     }
-    ids
+    deck
 }
 
 #[wasm_bindgen]
@@ -90,74 +481,283 @@ pub fn encode_ydke_main(ids: &[u32]) -> String {
 }
 
 #[wasm_bindgen]
-pub fn decode_ydke(ydke: &str, ignore_extra: bool) -> Vec<u32> {
+/// Encode all sections of the deck into a YDKE string
+pub fn encode_ydke(deck: &Deck) -> String {
+    let parts = Section::ALL
+        .iter()
+        .map(|&section| {
+            BASE64_STANDARD.encode(
+                deck.ids(section)
+                    .iter()
+                    .flat_map(|id| id.to_le_bytes())
+                    .collect::<Vec<u8>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("ydke://{}!", parts.join("!"))
+}
+
+/// Decode a YDKE string, with `ignore_extra` the Extra Deck is left empty.
+pub fn try_decode_ydke(ydke: &str, ignore_extra: bool) -> Result<Deck, YdkeError> {
+    let mut deck = try_decode_ydke_with_aliases(ydke, &AliasTable::new())?;
+    if ignore_extra {
+        deck.clear(Section::Extra);
+    }
+    Ok(deck)
+}
+
+/// Decode a YDKE string, alternate artworks are replaced by their canonical passcode.
+pub fn try_decode_ydke_with_aliases(ydke: &str, aliases: &AliasTable) -> Result<Deck, YdkeError> {
     let parts: Vec<_> = ydke
         .trim_start_matches("ydke://")
         .split('!')
         .take(3)
         .collect();
     let [main, extra, side] = parts.as_slice() else {
-        panic!("Expected exactly 3 parts");
+        return Err(YdkeError::MissingSections(parts.len()));
     };
 
-    let selected_parts = if ignore_extra {
-        vec![main, side]
-    } else {
-        vec![main, extra, side]
-    };
+    let main = decode_section(main, Section::Main)?;
+    let extra = decode_section(extra, Section::Extra)?;
+    let side = decode_section(side, Section::Side)?;
+    Ok(aliases.canonical_deck(&Deck::from_ids(&main, &extra, &side)))
+}
 
-    selected_parts
-        .iter()
-        .filter_map(|part| BASE64_STANDARD.decode(part).ok())
-        .map(|bytes| {
-            bytes
-                .chunks(4)
-                .filter_map(|chunk| {
-                    if chunk.len() == 4 {
-                        Some(u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<u32>>()
-        })
-        .flatten()
-        .collect()
+/// The passcodes of one base64 encoded section, 4 little endian bytes each.
+fn decode_section(part: &str, section: Section) -> Result<Vec<u32>, YdkeError> {
+    let bytes = BASE64_STANDARD
+        .decode(part)
+        .map_err(|_| YdkeError::InvalidSection(section))?;
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
+
+#[wasm_bindgen]
+/// Decode a YDKE string, with `ignore_extra` the Extra Deck is left empty
+pub fn decode_ydke(ydke: &str, ignore_extra: bool) -> Result<Deck, JsError> {
+    Ok(try_decode_ydke(ydke, ignore_extra)?)
+}
+
+#[wasm_bindgen]
+/// Decode a YDKE string, alternate artworks are replaced by their canonical passcode
+pub fn decode_ydke_with_aliases(ydke: &str, aliases: &AliasTable) -> Result<Deck, JsError> {
+    Ok(try_decode_ydke_with_aliases(ydke, aliases)?)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::monster::{Ability, Frame};
 
     #[test]
     fn test_csv() {
-        let _monsters = parse_csv(include_str!("../testing_data.csv"));
+        let _monsters = parse_csv(include_str!("../testing_data.csv")).unwrap();
         dbg!(&_monsters.len());
     }
 
+    #[test]
+    fn test_unknown_values() {
+        let data = "id,name,attribute,level,type,atk,def\n\
+            10000040,Holactie the Creator of Light,DIVINE,12,Creator God,-1,-1\n\
+            1,New Monster,LAUGH,4,Creator God,1000,1000\n\
+            89631139,Blue-Eyes White Dragon,LIGHT,8,Dragon,3000,2500\n";
+        let CsvDatabase {
+            monsters, warnings, ..
+        } = load_csv(data, false).unwrap();
+        assert_eq!(monsters.len(), 3);
+        assert_eq!(
+            monsters[0].r#type(),
+            &Type::Unknown("Creator God".to_string())
+        );
+        assert_eq!(monsters[1].attribute().to_string(), "LAUGH");
+        assert_eq!(
+            warnings.iter().map(|w| w.message()).collect::<Vec<_>>(),
+            [
+                "line 2: unknown type \"Creator God\"",
+                "line 3: unknown attribute \"LAUGH\"",
+                "line 3: unknown type \"Creator God\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_rows() {
+        let data = "id,name,attribute,level,type,atk,def\n\
+            89631139,Blue-Eyes White Dragon,LIGHT,8,Dragon,3000,2500\n\
+            14558127,Ash Blossom & Joyous Spring,FIRE,three,Zombie,0,1800\n\
+            23771716,7 Colored Fish,WATER,4,Fish,1800\n\
+            97268402,Effect Veiler,LIGHT,1,Spellcaster,0,-5\n\
+            36584821,Gren Maju Da Eiza,FIRE,3,Fiend,-1,-1\n";
+
+        let error = parse_csv(data).unwrap_err();
+        assert_eq!(
+            (error.row(), error.column(), error.value()),
+            (3, "level", "three")
+        );
+        assert_eq!(error.to_string(), "line 3: invalid level \"three\"");
+        assert!(parse_csv_file("missing.csv").is_err());
+
+        let CsvDatabase {
+            monsters, warnings, ..
+        } = load_csv(data, true).unwrap();
+        assert_eq!(
+            monsters.iter().map(|m| m.id()).collect::<Vec<_>>(),
+            [89631139, 36584821]
+        );
+        assert!(warnings.iter().all(|w| w.skipped()));
+        assert_eq!(
+            warnings.iter().map(|w| w.message()).collect::<Vec<_>>(),
+            [
+                "line 3: invalid level \"three\", the row was skipped",
+                "line 4: invalid def \"\", the row was skipped",
+                "line 5: invalid def \"-5\", the row was skipped",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_header_columns() {
+        let data = "# schema 2\n\
+            name,id,type,attribute,level,def,atk,frame,archetype,scale,banlist,release_date,views\n\
+            Ash Blossom & Joyous Spring,14558127,Zombie,FIRE,3,1800,0,effect,,,Limited,2016-02-11,9001\n\
+            Dinomist Rex,32134638,Dinosaur,WATER,6,1200,2400,effect_pendulum,Dinomist,6,,,\n";
+        let database = load_csv(data, false).unwrap();
+        assert_eq!(database.schema_version, 2);
+        assert!(database.warnings.is_empty());
+
+        let [ash, rex] = &database.monsters[..] else {
+            panic!("expected two monsters");
+        };
+        assert_eq!(
+            (ash.id(), ash.atk(), ash.def()),
+            (14558127, Some(0), Some(1800))
+        );
+        assert_eq!(ash.details().ban_status, BanStatus::Limited);
+        assert_eq!(ash.details().release_date.as_deref(), Some("2016-02-11"));
+        assert_eq!(ash.details().archetype, None);
+        assert_eq!(rex.details().frame, Some(Frame::EffectPendulum));
+        assert_eq!(rex.details().archetype.as_deref(), Some("Dinomist"));
+        assert_eq!(rex.details().scale, Some(6));
+        assert_eq!(rex.details().ban_status, BanStatus::Unlimited);
+
+        // files without the schema line are the original seven columns
+        assert_eq!(
+            load_csv(include_str!("../testing_data.csv"), false)
+                .unwrap()
+                .schema_version,
+            1
+        );
+
+        let error = load_csv("id,name,attribute,level,type,atk\n", true).unwrap_err();
+        assert_eq!(error.to_string(), "line 1: missing column \"def\"");
+        let error = load_csv("# version 3\nid\n", false).unwrap_err();
+        assert_eq!((error.row(), error.column()), (1, "schema"));
+    }
+
+    #[test]
+    fn test_alias_column() {
+        let data = "# schema 4\n\
+            id,name,attribute,level,type,atk,def,aliases\n\
+            89631139,Blue-Eyes White Dragon,LIGHT,8,Dragon,3000,2500,89631140 89631141\n\
+            14558127,Ash Blossom & Joyous Spring,FIRE,3,Zombie,0,1800,\n";
+        let database = load_csv(data, false).unwrap();
+        assert_eq!(database.aliases.len(), 2);
+        assert_eq!(database.aliases.canonical(89631141), 89631139);
+
+        let data = data.replace("89631141", "x");
+        let error = load_csv(&data, false).unwrap_err();
+        assert_eq!(
+            (error.row(), error.column(), error.value()),
+            (3, "aliases", "x")
+        );
+    }
+
+    #[test]
+    fn test_other_cards() {
+        let data = "# schema 4\n\
+            id,name,attribute,level,type,atk,def,frame,aliases\n\
+            14558127,Ash Blossom & Joyous Spring,FIRE,3,Zombie,0,1800,effect,\n\
+            93850690,Small World,,,,,,spell,\n\
+            44052075,Extra Deck Monster,DARK,,,,,fusion,44052076\n";
+        let database = load_csv(data, false).unwrap();
+        assert_eq!(database.monsters.len(), 1);
+        assert_eq!(database.other_cards, vec![93850690, 44052075]);
+        assert_eq!(database.aliases.canonical(44052076), 44052075);
+        assert!(database.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_abilities() {
+        let data = "# schema 3\n\
+            id,name,attribute,level,type,atk,def,frame,abilities\n\
+            14558127,Ash Blossom & Joyous Spring,FIRE,3,Zombie,0,1800,effect,Tuner\n\
+            78636495,Lyrilusc - Cobalt Sparrow,WIND,1,Winged Beast,0,0,effect,\n\
+            1,Future Monster,DARK,4,Fiend,0,0,effect_link,Tuner Spirit Shark\n";
+        let database = load_csv(data, false).unwrap();
+        let [ash, sparrow, future] = &database.monsters[..] else {
+            panic!("expected three monsters");
+        };
+        assert!(ash.has_ability(Ability::Tuner));
+        assert!(sparrow.abilities().is_empty());
+        assert_eq!(sparrow.details().frame, Some(Frame::Effect));
+        assert_eq!(future.details().frame, None);
+        assert_eq!(future.abilities(), &[Ability::Tuner, Ability::Spirit]);
+        assert_eq!(
+            database
+                .warnings
+                .iter()
+                .map(|w| w.message())
+                .collect::<Vec<_>>(),
+            [
+                "line 5: unknown frame \"effect_link\"",
+                "line 5: unknown ability \"Shark\"",
+            ]
+        );
+    }
+
     #[test]
     fn test_ydke_decode_no_side() {
         let ydke = "ydke://R7x9AEe8fQBHvH0AMdwRATHcEQEx3BEBeA09AxNWxAMTVsQDE1bEA6OpVwWjqVcFryPeAK8j3gCvI94AOLFjBDixYwQ4sWME/omcBf6JnAWyMswFNQeDAjUHgwI1B4MCdDleA3Q5XgN0OV4DIfa7AYoMdAG1dg4BAa/JBAGvyQQBr8kEYmqzA6p4kwLpzMgF6czIBenMyAUiSJkAIkiZAA==!gZ1eA92drgDUc6AAgQqVAjXQkAM10JADNdCQA81CVwXjUkIBg/jHA8oavwGWunMBlrpzAQJcggICXIIC!!";
-        let ids = decode_ydke(ydke, true);
-        println!("{:?}", ids);
-        assert_eq!(ids.len(), 40);
-        assert_eq!(ids[0], 8240199);
+        let deck = try_decode_ydke(ydke, false).unwrap();
+        assert_eq!(deck.len(Section::Main), 40);
+        assert_eq!(deck.len(Section::Extra), 15);
+        assert_eq!(deck.len(Section::Side), 0);
+        assert_eq!(deck.ids(Section::Main)[0], 8240199);
+        assert_eq!(try_decode_ydke(ydke, true).unwrap().len(Section::Extra), 0);
+    }
+
+    #[test]
+    fn test_ydke_errors() {
+        let error = |ydke: &str| try_decode_ydke(ydke, false).unwrap_err();
+        assert_eq!(error("ydke://R7x9AA==!"), YdkeError::MissingSections(2));
+        assert_eq!(error(""), YdkeError::MissingSections(1));
+        assert_eq!(
+            error("ydke://R7x9AA==!not base64!!"),
+            YdkeError::InvalidSection(Section::Extra)
+        );
+        assert_eq!(
+            error("ydke://R7x9AA==").to_string(),
+            "expected 3 sections separated by '!', found 1"
+        );
     }
 
     //OfUdBKOpVwU1B4MCeA09A9canwGt4goCoJQEArIyzAU4sWME b69ADHcEQHBcjEFE1bEA/6JnAVHvH0ARK0EBa8j3gA=
     #[test]
     fn test_ydke_decodes_2() {
         let ydke = "ydke://OfUdBKOpVwU1B4MCeA09A9canwGt4goCoJQEArIyzAU4sWME+b69ADHcEQHBcjEFE1bEA/6JnAVHvH0ARK0EBa8j3gA=!!!";
-        let ids = decode_ydke(ydke, true);
-        assert_eq!(ids.len(), 17);
+        let deck = try_decode_ydke(ydke, false).unwrap();
+        assert_eq!(deck.len(Section::Main), 17);
     }
 
     #[test]
     fn test_ydke_decode() {
         let ydke = "ydke://R7x9AEe8fQBHvH0AMdwRATHcEQEx3BEBeA09AxNWxAMTVsQDE1bEA6OpVwWjqVcFryPeAK8j3gCvI94AOLFjBDixYwQ4sWME/omcBf6JnAWyMswFNQeDAjUHgwI1B4MCdDleA3Q5XgN0OV4DIfa7AYoMdAG1dg4BAa/JBAGvyQQBr8kEYmqzA6p4kwLpzMgF6czIBenMyAUiSJkAIkiZAA==!gZ1eA92drgDUc6AAgQqVAjXQkAM10JADNdCQA81CVwXjUkIBg/jHA8oavwGWunMBlrpzAQJcggICXIIC!RK0EBUStBAXBcjEFwXIxBfm+vQCglAQCOfUdBK3iCgKt4goCreIKAtcanwHXGp8BYr4XBWK+FwVivhcF!";
-        let ids = decode_ydke(ydke, true);
-        assert_eq!(ids.len(), 55);
-        assert_eq!(ids[0], 8240199);
+        let deck = try_decode_ydke(ydke, false).unwrap();
+        assert_eq!(deck.len(Section::Main) + deck.len(Section::Side), 55);
+        assert_eq!(deck.ids(Section::Main)[0], 8240199);
+        assert_eq!(encode_ydke(&deck), ydke);
     }
 
     #[test]
@@ -252,8 +852,13 @@ mod tests {
 85442146
 85442146
 "#;
-        let ids = parse_ydk(ydk, true);
-        assert_eq!(ids.len(), 55);
+        let deck = parse_ydk(ydk, true);
+        assert_eq!(deck.len(Section::Main) + deck.len(Section::Side), 55);
+        assert_eq!(deck.len(Section::Extra), 0);
+        assert_eq!(
+            deck.ids(Section::Main),
+            parse_ydk(ydk, false).ids(Section::Main)
+        );
     }
 
     #[test]
@@ -332,9 +937,28 @@ mod tests {
 85442146
 85442146
 "#;
-        let ids = parse_ydk(ydk, false);
-        assert_eq!(ids.len(), 70);
-        assert_eq!(ids[0], 8240199);
-        assert_eq!(ids[ids.len() - 1], 85442146);
+        let deck = parse_ydk(ydk, false);
+        assert_eq!(deck.len(Section::Main), 40);
+        assert_eq!(deck.len(Section::Extra), 15);
+        assert_eq!(deck.len(Section::Side), 15);
+        assert_eq!(deck.ids(Section::Main)[0], 8240199);
+        assert_eq!(*deck.ids(Section::Side).last().unwrap(), 85442146);
+        assert_eq!(deck.count(Section::Main, 8240199), 3);
+    }
+
+    #[test]
+    fn test_aliases() {
+        let aliases = AliasTable::try_from_csv("alias,id\n89631140,89631139\n").unwrap();
+        let deck = parse_ydk_with_aliases(
+            "#main\n89631139\n89631140\n#extra\n!side\n89631140\n",
+            &aliases,
+        );
+        assert_eq!(deck.count(Section::Main, 89631139), 2);
+        assert_eq!(deck.count(Section::Side, 89631139), 1);
+        assert_eq!(deck.copies(89631140), 0);
+
+        let ydke = encode_ydke(&Deck::from_ids(&[89631140, 1], &[], &[]));
+        let deck = try_decode_ydke_with_aliases(&ydke, &aliases).unwrap();
+        assert_eq!(deck.unique_ids(Section::Main), vec![89631139, 1]);
     }
 }
@@ -5,10 +5,11 @@ import requests
 CARD_INFO = "https://db.ygoprodeck.com/api/v7/cardinfo.php"
 JSON_PATH = "../resources/cardinfo_full.json" # https://www.ygoprodeck.com/api-guide/ , v7 ygoprodeck api json dump
 CSV_PATH = "../resources/m.csv"
+SCHEMA_VERSION = 4 # keep in sync with SCHEMA_VERSION in lib/src/util.rs
 
 print(f"Fetching card list from ygoprodeck...")
 
-response = requests.get(CARD_INFO)
+response = requests.get(CARD_INFO, params={"misc": "yes"})
 response.raise_for_status()
 data = response.json()['data']
 
@@ -21,13 +22,10 @@ df = pd.DataFrame(data)
 
 print(f"Found {len(df)} cards")
 
-# Filter main deck monsters
-df = df[df['type'].str.contains("Monster")]
+# Main deck monsters, Spells, Traps and Extra Deck monsters only keep their id, frame and aliases
+# so the searcher can tell them apart from unknown passcodes
 main_deck_frames = ['effect', 'normal', 'ritual', 'effect_pendulum', 'normal_pendulum', 'ritual_pendulum']
-df = df[df['frameType'].isin(main_deck_frames)]
-
-# Filter 10000040,Holactie the Creator of Light,DIVINE,12,Creator God,-1,-1
-df = df[df['id'] != 10000040]
+main_deck = df['type'].str.contains("Monster") & df['frameType'].isin(main_deck_frames)
 
 def clean_stat(val):
     try:
@@ -40,9 +38,39 @@ df['def'] = df['def'].apply(clean_stat)
 df['level'] = df['level'].apply(clean_stat)
 
 df['type'] = df['race']
-target_cols = ['id', 'name', 'attribute', 'level', 'type', 'atk', 'def']
-df = df[target_cols]
 
-print(f"Filtered down to {len(df)} monsters.")
-df.to_csv(CSV_PATH, index=False)
+# Optional columns, empty if unknown
+df['frame'] = df['frameType']
+ABILITIES = ['Tuner', 'Flip', 'Gemini', 'Spirit', 'Toon', 'Union']
+df['abilities'] = [
+    ' '.join(t for t in typeline if t in ABILITIES) if isinstance(typeline, list) else ''
+    for typeline in df['typeline']
+]
+df['archetype'] = df['archetype'].fillna('') if 'archetype' in df else ''
+df['scale'] = df['scale'].astype('Int64') if 'scale' in df else pd.NA
+df['banlist'] = [
+    info.get('ban_tcg', '') if isinstance(info, dict) else ''
+    for info in (df['banlist_info'] if 'banlist_info' in df else [None] * len(df))
+]
+df['release_date'] = [
+    misc[0].get('tcg_date', '') if isinstance(misc, list) and misc else ''
+    for misc in df['misc_info']
+]
+
+# Alternate artworks have their own passcode, the searcher maps them to the passcode of the row
+df['aliases'] = [
+    ' '.join(str(image['id']) for image in images if image['id'] != card_id)
+    for card_id, images in zip(df['id'], df['card_images'])
+]
+
+target_cols = ['id', 'name', 'attribute', 'level', 'type', 'atk', 'def',
+               'frame', 'abilities', 'archetype', 'scale', 'banlist', 'release_date', 'aliases']
+df = df[target_cols].astype(object)
+other_cols = [col for col in target_cols if col not in ('id', 'frame', 'aliases')]
+df.loc[~main_deck, other_cols] = ''
+
+print(f"Found {main_deck.sum()} main deck monsters and {(~main_deck).sum()} other cards.")
+with open(CSV_PATH, "w", encoding="utf-8", newline='') as f:
+    f.write(f"# schema {SCHEMA_VERSION}\n")
+    df.to_csv(f, index=False)
 print(f"Saved to {CSV_PATH}")
@@ -57,11 +57,16 @@ def main():
     with open(CSV_PATH, "r", encoding="utf-8") as f:
         lines = f.readlines()
 
+    # the header follows the optional "# schema <version>" line, columns are appended in its order
+    lines = [line for line in lines if not line.startswith('#')]
+    header = lines[0].strip().split(',')
+    id_column = header.index('id')
+
     existing_ids = set()
     for line in lines[1:]:
         parts = line.strip().split(',')
-        if parts:
-            existing_ids.add(int(parts[0]))
+        if len(parts) > id_column:
+            existing_ids.add(int(parts[id_column]))
 
     new_monsters = df[~df['id'].isin(existing_ids)]
 
@@ -75,8 +80,22 @@ def main():
     confirm_action(f"Ready to append {len(new_monsters)} monsters to {CSV_PATH}. Proceed?")
 
     with open(CSV_PATH, "a", encoding="utf-8", newline='') as f:
-        target_cols = ['id', 'name', 'attribute', 'level', 'type', 'atk', 'def']
-        csv_data = new_monsters[target_cols]
+        if 'frame' in header:
+            new_monsters = new_monsters.assign(frame=new_monsters['frameType'])
+        if 'abilities' in header:
+            abilities = ['Tuner', 'Flip', 'Gemini', 'Spirit', 'Toon', 'Union']
+            new_monsters = new_monsters.assign(abilities=[
+                ' '.join(t for t in typeline if t in abilities) if isinstance(typeline, list) else ''
+                for typeline in new_monsters['typeline']
+            ])
+        if 'aliases' in header:
+            new_monsters = new_monsters.assign(aliases=[
+                ' '.join(str(image['id']) for image in images if image['id'] != card_id)
+                for card_id, images in zip(new_monsters['id'], new_monsters['card_images'])
+            ])
+        if 'scale' in new_monsters:
+            new_monsters = new_monsters.assign(scale=new_monsters['scale'].astype('Int64'))
+        csv_data = new_monsters.reindex(columns=header, fill_value='')
         csv_data.to_csv(f, header=False, index=False)
 
     print(f"Appended {len(new_monsters)} new monsters to {CSV_PATH}")
@@ -1,7 +1,14 @@
 import { Upload } from 'lucide-preact';
 import { useEffect, useRef } from 'react';
 import { Button } from '.';
-import { decode_ydke, parse_ydk } from '../wasm';
+import { Section, decode_ydke, parse_ydk } from '../wasm';
+
+// extra deck monsters can not be searched with Small World
+function searchableIds(deck) {
+    return [
+        ...new Set([...deck.ids(Section.Main), ...deck.ids(Section.Side)]),
+    ];
+}
 
 function readFileAsText(file) {
     return new Promise((resolve, reject) => {
@@ -18,8 +25,7 @@ export function DeckInput({ onInput }) {
     const handleFile = async (file) => {
         if (!file) return;
         const text = await readFileAsText(file);
-        const cardList = parse_ydk(text, true);
-        onInput([...new Set(cardList)]);
+        onInput(searchableIds(parse_ydk(text, true)));
         inputRef.current.value = null;
     };
 
@@ -45,14 +51,19 @@ export function DeckInput({ onInput }) {
                 await handleFile(item.getAsFile());
             } else if (item.kind === 'string' && item.type === 'text/plain') {
                 const text = e.clipboardData.getData('text');
-                const cards = text.startsWith('ydke://')
-                    ? decode_ydke(text, true)
-                    : text.startsWith('#main')
-                      ? parse_ydk(text, true)
-                      : null;
+                let deck = null;
+                try {
+                    deck = text.startsWith('ydke://')
+                        ? decode_ydke(text, true)
+                        : text.startsWith('#main')
+                          ? parse_ydk(text, true)
+                          : null;
+                } catch (error) {
+                    console.error('wasm: invalid deck in clipboard', error);
+                }
 
-                if (cards) {
-                    onInput([...new Set(cards)]);
+                if (deck) {
+                    onInput(searchableIds(deck));
                 } else {
                     alert('Unrecognized deck format in clipboard.');
                 }
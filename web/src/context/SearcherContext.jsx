@@ -1,7 +1,7 @@
 import { useQuery } from '@tanstack/react-query';
 import { createContext, useEffect, useRef, useState } from 'react';
 import { fetchCsv } from '../api/smallWorld.js';
-import { SmallWorldSearcher } from '../wasm/index.js';
+import { SmallWorldSearcher, csv_schema_version } from '../wasm/index.js';
 
 export const SearcherContext = createContext(null);
 
@@ -15,10 +15,26 @@ export function SearcherProvider({ children }) {
 
     const searcherRef = useRef(null);
     const [isReady, setIsReady] = useState(false);
+    const [isStale, setIsStale] = useState(false);
+    const [loadError, setLoadError] = useState(null);
 
     useEffect(() => {
         if (isSuccess && csvData && !searcherRef.current) {
-            searcherRef.current = new SmallWorldSearcher(csvData);
+            try {
+                searcherRef.current = new SmallWorldSearcher(csvData);
+            } catch (error) {
+                console.error('wasm: failed to load the card database', error);
+                setLoadError(error);
+                return;
+            }
+
+            // a cached card database older than this build misses columns, e.g. alternate artworks
+            const schemaVersion = searcherRef.current.schema_version;
+            if (schemaVersion < csv_schema_version()) {
+                console.warn(`wasm: card database has schema ${schemaVersion}, expected ${csv_schema_version()}`);
+                setIsStale(true);
+            }
+
             setIsReady(true);
             console.log('wasm: created SmallWorldSearcher instance');
         }
@@ -35,6 +51,8 @@ export function SearcherProvider({ children }) {
     const contextValue = {
         searcher: searcherRef.current,
         isSearcherLoading: !isReady,
+        isDatabaseStale: isStale,
+        searcherError: loadError,
     };
 
     return (
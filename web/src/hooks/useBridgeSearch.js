@@ -20,7 +20,7 @@ export function useBridgeSearch() {
         const commonBridges = searcher.find_common_bridges(
             inHandList.map((item) => item.id),
             targetList.map((item) => item.id),
-        );
+        ).monsters;
 
         commonBridges.sort((a, b) => a.name_js.localeCompare(b.name_js));
 
@@ -14,41 +14,27 @@ export function useGraphData(cards, searcher) {
             idToCard.set(card.id, card);
         });
 
-        const links = searcher
-            .compute_links_within(cards.map((card) => card.id))
-            .map((l) => {
-                return {
-                    source: idToCard.get(l.start.id),
-                    bridge: idToCard.get(l.bridge.id),
-                    target: idToCard.get(l.target.id),
-                };
-            });
-
-        // Filter out symmetric links
-        const seen = new Set();
-        const filteredLinks = links.filter((link) => {
-            const key1 = `${link.source.id}-${link.bridge.id}-${link.target.id}`;
-            const key2 = `${link.target.id}-${link.bridge.id}-${link.source.id}`;
-            if (seen.has(key1) || seen.has(key2)) {
-                return false;
-            }
-            seen.add(key1);
-            return true;
-        });
+        // symmetric links are merged and grouped by source-target in wasm
+        const groups = searcher.compute_link_groups_within(
+            cards.map((card) => card.id),
+        );
+        const sources = groups.sources;
+        const targets = groups.targets;
+        const offsets = groups.bridge_offsets;
+        const bridgeIds = groups.bridges;
 
-        // Group links by source-target
         const linkMap = new Map();
-        filteredLinks.forEach((link) => {
-            const key = `${link.source.id}-${link.target.id}`;
-            if (!linkMap.has(key)) {
-                linkMap.set(key, {
-                    source: link.source,
-                    target: link.target,
-                    bridges: [],
-                });
-            }
-            linkMap.get(key).bridges.push(link.bridge);
+        sources.forEach((sourceId, i) => {
+            linkMap.set(`${sourceId}-${targets[i]}`, {
+                source: idToCard.get(sourceId),
+                target: idToCard.get(targets[i]),
+                bridges: Array.from(
+                    bridgeIds.subarray(offsets[i], offsets[i + 1]),
+                    (id) => idToCard.get(id),
+                ),
+            });
         });
+        groups.free();
 
         //! this does create circular references
         nodes.forEach((node) => {
@@ -13,7 +13,7 @@ import {
 import { deckCodesSignal } from '../core/signals.js';
 import { useGraphData, useSearcher } from '../hooks';
 import { cloneWasmCard, cn } from '../utils';
-import { decode_ydke, encode_ydke_main } from '../wasm';
+import { Section, decode_ydke, encode_ydke_main } from '../wasm';
 
 export function DeckView() {
     const { searcher, isSearcherLoading } = useSearcher();
@@ -24,8 +24,12 @@ export function DeckView() {
 
     useEffect(() => {
         if (ydkeUrlParam && deckCodesSignal.value === null) {
-            const ids = decode_ydke(`ydke://${ydkeUrlParam}!!!`);
-            deckCodesSignal.value = Array.from(ids);
+            try {
+                const deck = decode_ydke(`ydke://${ydkeUrlParam}!!!`, false);
+                deckCodesSignal.value = Array.from(deck.ids(Section.Main));
+            } catch (error) {
+                console.error('wasm: invalid ydke in the url', error);
+            }
         }
     }, []);
 
@@ -1,6 +1,417 @@
 /* tslint:disable */
 /* eslint-disable */
 
+/**
+ * Monster abilities printed next to the type, e.g. "Spellcaster/Tuner".
+ */
+export enum Ability {
+  Tuner = 0,
+  Flip = 1,
+  Gemini = 2,
+  Spirit = 3,
+  Toon = 4,
+  Union = 5,
+}
+
+/**
+ * Why a Small World activation can not be resolved.
+ */
+export enum ActivationError {
+  NoSmallWorld = 0,
+  RevealNotInHand = 1,
+  BridgeNotInDeck = 2,
+  TargetNotInDeck = 3,
+  NotAMonster = 4,
+  BridgeNotConnected = 5,
+  TargetNotConnected = 6,
+}
+
+export class AliasTable {
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * A copy of the deck with every passcode replaced by its canonical one,
+   * copies of different artworks are merged.
+   */
+  canonical_deck(deck: Deck): Deck;
+  len(): number;
+  constructor();
+  /**
+   * Parse a CSV file with the columns `alias,id`, e.g. for a card database without aliases.
+   */
+  static from_csv(data: string): AliasTable;
+  is_alias(id: number): boolean;
+  is_empty(): boolean;
+  /**
+   * The canonical passcode, passcodes without alias are returned unchanged.
+   */
+  canonical(id: number): number;
+}
+
+/**
+ * The status of a card on the banlist.
+ */
+export enum BanStatus {
+  Forbidden = 0,
+  Limited = 1,
+  SemiLimited = 2,
+  Unlimited = 3,
+}
+
+export class Bridges {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  readonly monsters: Monster[];
+}
+
+export class CardFilter {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  static parse(query: string): CardFilter;
+  /**
+   * whether the filter has no terms and matches every monster
+   */
+  is_empty(): boolean;
+}
+
+export class Chain {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * minimum number of Small World activations needed to reach the target
+   */
+  readonly activations: number;
+  /**
+   * hand, bridge, target of the 1st activation, bridge, target of the 2nd activation, ...
+   */
+  readonly path: Uint32Array;
+  readonly target: number;
+}
+
+export class Chains {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  readonly chains: Chain[];
+}
+
+export class ConnectionRule {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * The Small World rule: exactly one of attribute, level, type, ATK and DEF.
+   */
+  static small_world(): ConnectionRule;
+  /**
+   * Monsters may share at most n of the given properties.
+   */
+  static at_most(properties: any[], n: number): ConnectionRule;
+  /**
+   * Monsters have to share exactly n of the given properties.
+   */
+  static exactly(properties: any[], n: number): ConnectionRule;
+  /**
+   * Monsters have to share at least n of the given properties.
+   */
+  static at_least(properties: any[], n: number): ConnectionRule;
+}
+
+export class CsvWarning {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  readonly row: number;
+  readonly value: string;
+  readonly column: string;
+  readonly message: string;
+  /**
+   * whether the monster of the row is missing from the database
+   */
+  readonly skipped: boolean;
+}
+
+export class Deck {
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * Passcodes of the section without duplicates.
+   */
+  unique_ids(section: Section): Uint32Array;
+  add(section: Section, id: number, copies: number): void;
+  /**
+   * All passcodes of the section, one entry per copy.
+   */
+  ids(section: Section): Uint32Array;
+  /**
+   * Number of cards in the section.
+   */
+  len(section: Section): number;
+  constructor();
+  /**
+   * Every change needed to turn this deck into `other`.
+   */
+  diff(other: Deck): DeckChange[];
+  /**
+   * Copies of the passcode in the section.
+   */
+  count(section: Section, id: number): number;
+  /**
+   * Copies of the passcode over all sections.
+   */
+  copies(id: number): number;
+  /**
+   * Copies of every passcode, in the order of `unique_ids`.
+   */
+  counts(section: Section): Uint32Array;
+  /**
+   * Removes up to `copies` copies, returns how many were removed.
+   */
+  remove(section: Section, id: number, copies: number): number;
+  is_empty(): boolean;
+  /**
+   * Checks section sizes and the copy limit, returns every violated rule.
+   */
+  validate(): DeckIssue[];
+}
+
+export class DeckChange {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  readonly id: number;
+  /**
+   * copies in the other deck
+   */
+  readonly to: number;
+  /**
+   * copies in the original deck
+   */
+  readonly from: number;
+  readonly section: Section;
+}
+
+export class DeckGraphStats {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * connected component of each card, numbered from 0
+   */
+  readonly components: Uint32Array;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  /**
+   * cards no other card can reach
+   */
+  readonly unreachable: Uint32Array;
+  /**
+   * share of each bridge in connecting the pairs of the deck: every unordered pair that can
+   * be linked counts 1, split evenly over all of its bridges. Unlike graph betweenness,
+   * only the direct reveal -> bridge -> target paths are counted, not shortest paths.
+   */
+  readonly bridge_share: Float64Array;
+  /**
+   * number of pairs each critical bridge is the only route for
+   */
+  readonly critical_pairs: Uint32Array;
+  readonly component_count: number;
+  /**
+   * bridges that are the only route for at least one pair
+   */
+  readonly critical_bridges: Uint32Array;
+  /**
+   * every card of the deck known to the searcher
+   */
+  readonly ids: Uint32Array;
+  /**
+   * every card that acts as bridge for at least one pair
+   */
+  readonly bridges: Uint32Array;
+  /**
+   * number of cards each card can reach
+   */
+  readonly degrees: Uint32Array;
+  /**
+   * cards that can not reach any other card
+   */
+  readonly dead_ends: Uint32Array;
+}
+
+export class DeckIssue {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  readonly id: number | undefined;
+  readonly kind: DeckIssueKind;
+  readonly count: number;
+  readonly message: string;
+}
+
+export enum DeckIssueKind {
+  MainDeckTooSmall = 0,
+  MainDeckTooLarge = 1,
+  ExtraDeckTooLarge = 2,
+  SideDeckTooLarge = 3,
+  TooManyCopies = 4,
+}
+
+export class Diagnostics {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * passcodes that were left out of the query, aliases are not counted
+   */
+  dropped_ids(): Uint32Array;
+  /**
+   * whether every passcode was used as given
+   */
+  is_empty(): boolean;
+  /**
+   * ignored and replaced passcodes, in the order they appeared in the query
+   */
+  readonly ignored: IgnoredId[];
+}
+
+export class FilterError {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  readonly message: string;
+  /**
+   * character offset in the query where the error was found
+   */
+  readonly position: number;
+}
+
+export class FilteredIds {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  readonly ids: Uint32Array;
+}
+
+/**
+ * The card frame of a Main Deck monster.
+ */
+export enum Frame {
+  Normal = 0,
+  Effect = 1,
+  Ritual = 2,
+  NormalPendulum = 3,
+  EffectPendulum = 4,
+  RitualPendulum = 5,
+}
+
+export class GameState {
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * Resolves Small World: the revealed monster is banished face-down from the hand,
+   * the target is added from the deck to the hand, then the bridge is banished face-down
+   * from the deck. Small World itself is sent to the graveyard.
+   * Nothing changes if the activation is not legal.
+   */
+  apply_small_world(searcher: SmallWorldSearcher, reveal: number, bridge: number, target: number): void;
+  /**
+   * Checks whether Small World can be resolved with the given cards, without changing the zones.
+   */
+  check_small_world(searcher: SmallWorldSearcher, reveal: number, bridge: number, target: number): ActivationError | undefined;
+  /**
+   * `deck` holds the cards left in the main deck, without the hand.
+   * Passcodes are resolved with the alias table of the searcher.
+   */
+  constructor(hand_ids: Uint32Array, deck: Deck, searcher: SmallWorldSearcher);
+  /**
+   * Moves a copy of the card from the deck to the hand, returns false if none is left.
+   */
+  draw(id: number): boolean;
+  /**
+   * Every line that can be played from the current hand into the current deck.
+   */
+  lines(searcher: SmallWorldSearcher): HandLines;
+  readonly deck: Deck;
+  readonly hand: Uint32Array;
+  /**
+   * cards banished face-down, in the order they were banished
+   */
+  readonly banished: Uint32Array;
+  /**
+   * resolved copies of Small World
+   */
+  readonly graveyard: Uint32Array;
+}
+
+export class HandLines {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  /**
+   * whether Small World is in the hand, without it none of the lines can be played
+   */
+  readonly has_small_world: boolean;
+  readonly lines: PathExplanation[];
+}
+
+export class Hop {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  readonly properties: any[];
+  readonly values: string[];
+}
+
+export enum IgnoreReason {
+  /**
+   * the passcode is unknown
+   */
+  NotInDatabase = 0,
+  /**
+   * a Spell, Trap or Extra Deck monster, Small World can not search it
+   */
+  NotMainDeckMonster = 1,
+  /**
+   * an alternate artwork, the query used the canonical passcode instead
+   */
+  AliasResolved = 2,
+  /**
+   * a monster past the 128 distinct monsters the odds of a deck are computed for
+   */
+  OverMonsterLimit = 3,
+}
+
+export class IgnoredId {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  readonly id: number;
+  readonly reason: IgnoreReason;
+  readonly message: string;
+  readonly canonical: number | undefined;
+}
+
 export class Link {
   private constructor();
   free(): void;
@@ -10,82 +421,718 @@ export class Link {
   readonly target: Monster;
 }
 
+export class LinkGroups {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  is_empty(): boolean;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  /**
+   * start of every group in `bridges`, followed by the total number of bridges
+   */
+  readonly bridge_offsets: Uint32Array;
+  /**
+   * number of groups
+   */
+  readonly len: number;
+  readonly bridges: Uint32Array;
+  readonly sources: Uint32Array;
+  readonly targets: Uint32Array;
+}
+
+export class Links {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  readonly links: Link[];
+}
+
 export class Monster {
   private constructor();
   free(): void;
   [Symbol.dispose](): void;
+  readonly ban_status: BanStatus;
+  readonly abilities_js: any[];
   readonly attribute_js: string;
+  readonly release_date: string | undefined;
   readonly id: number;
   readonly atk: number | undefined;
   readonly def: number | undefined;
+  /**
+   * `None` if the card database has no frames
+   */
+  readonly frame: Frame | undefined;
   readonly level: number;
+  readonly scale: number | undefined;
   readonly name_js: string;
   readonly type_js: string;
+  readonly archetype: string | undefined;
+}
+
+export class OpeningOdds {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * probability to reach at least one of the targets
+   */
+  readonly any_target: number;
+  /**
+   * probability to reach each target, in the order of `targets`
+   */
+  readonly per_target: Float64Array;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  /**
+   * probability to open at least one Small World
+   */
+  readonly small_world: number;
+  readonly targets: Uint32Array;
+}
+
+export class OptimizedDeck {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  /**
+   * number of decks whose odds were computed
+   */
+  readonly evaluations: number;
+  /**
+   * the core with the selected candidates added to the main deck
+   */
+  readonly deck: Deck;
+  /**
+   * mean probability to reach each target
+   */
+  readonly coverage: number;
+}
+
+export class OptimizerConfig {
+  free(): void;
+  [Symbol.dispose](): void;
+  constructor(budget: number, hand_size: number);
+  /**
+   * maximum number of cards added to the core
+   */
+  budget: number;
+  hand_size: number;
+  strategy: OptimizerStrategy;
+  /**
+   * number of swaps tried by the local search
+   */
+  iterations: number;
+  seed: bigint;
+}
+
+export enum OptimizerStrategy {
+  /**
+   * add the best single copy until the budget is used or nothing improves
+   */
+  Greedy = 0,
+  /**
+   * greedy, followed by random swaps of added copies that are kept if they improve
+   */
+  LocalSearch = 1,
+}
+
+export class PathExplanation {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  readonly hand_to_bridge: Hop;
+  readonly bridge_to_target: Hop;
+  readonly hand: number;
+  readonly bridge: number;
+  readonly target: number;
+}
+
+export class PathGroup {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  readonly hand: number;
+  readonly paths: PathExplanation[];
+  readonly target: number;
+}
+
+export class PathGroups {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  readonly groups: PathGroup[];
+}
+
+/**
+ * The properties compared by Small World.
+ */
+export enum Property {
+  Attribute = 0,
+  Level = 1,
+  Type = 2,
+  Atk = 3,
+  Def = 4,
+}
+
+export class Recommendation {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * number of unordered hand/target pairs the bridge newly connects
+   */
+  readonly new_connections: number;
+  readonly bridge: number;
+  /**
+   * deck monsters the bridge connects to
+   */
+  readonly connects: Uint32Array;
+}
+
+export class Recommendations {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  readonly recommendations: Recommendation[];
+}
+
+export enum Section {
+  Main = 0,
+  Extra = 1,
+  Side = 2,
+}
+
+export class SimulationResult {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * number of hands that reached at least one target
+   */
+  readonly any_target: number;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  /**
+   * number of hands that opened Small World
+   */
+  readonly small_world: number;
+  readonly lower_bounds: Float64Array;
+  readonly upper_bounds: Float64Array;
+  /**
+   * success rate of each target
+   */
+  readonly rates: Float64Array;
+  readonly trials: number;
+  readonly targets: Uint32Array;
+  /**
+   * number of hands that reached each target, in the order of `targets`
+   */
+  readonly successes: Uint32Array;
 }
 
 export class SmallWorldSearcher {
   free(): void;
   [Symbol.dispose](): void;
+  /**
+   * The passcodes of monsters that match the filter, in the given order,
+   * e.g. to narrow down bridge results. Passcodes not in the database are dropped.
+   */
+  filter_ids(filter: CardFilter, ids: Uint32Array): FilteredIds;
+  /**
+   * Find every path hand -> bridge -> target for all given hand and target monsters.
+   * Paths are grouped per hand/target pair, pairs without any bridge are left out.
+   */
+  find_paths(hand_ids: Uint32Array, target_ids: Uint32Array): PathGroups;
+  /**
+   * Find every pool monster reachable from the hand monster with at most `max_activations`
+   * consecutive Small World resolutions, where each target is revealed for the next activation.
+   * Every target is reported with the minimum number of activations and one shortest path.
+   */
+  find_chains(hand_id: number, pool_ids: Uint32Array, max_activations: number): Chains;
+  /**
+   * Replace the alternate artwork passcodes loaded with the card database, all following
+   * queries accept them in place of the canonical passcodes.
+   */
+  set_aliases(aliases: AliasTable): void;
+  /**
+   * The passcode the database uses for the monster, resolving alternate artworks.
+   * `None` if the monster is not in the database.
+   */
+  canonical_id(id: number): number | undefined;
+  /**
+   * Explain the path hand -> bridge -> target by the properties shared on each hop.
+   * Returns None if one of the monsters is unknown or a hop is not a valid connection.
+   */
+  explain_path(hand: number, bridge: number, target: number): PathExplanation | undefined;
+  /**
+   * Find every monster that can reach the target through at least one bridge.
+   * If a pool is given, both the source and the bridge have to be part of it,
+   * otherwise the whole card pool is searched. The target itself is never a source.
+   */
+  find_sources(target_id: number, pool_ids?: Uint32Array | null): Sources;
+  /**
+   * Exact probability to open Small World together with a monster that reaches the targets,
+   * for a hand of the given size drawn from the main deck. See `probability::opening_odds`.
+   */
+  opening_odds(deck: Deck, hand_size: number, target_ids: Uint32Array): OpeningOdds;
+  /**
+   * Parse a filter expression like `CardFilter.parse`, attributes and types this version
+   * does not know are accepted too if the card database has them.
+   */
+  parse_filter(query: string): CardFilter;
+  /**
+   * Passcode of the monster with the given name, allowing for small typos,
+   * e.g. to import a plain-text decklist. `None` if no name is close enough.
+   */
+  resolve_name(name: string): number | undefined;
+  /**
+   * Passcodes of the monsters whose names match the query best, best first.
+   * Tolerates typos and ignores case, punctuation and hyphens.
+   */
+  search_names(query: string, limit: number): Uint32Array;
+  /**
+   * Attributes and types of the card database this version does not know, and skipped rows.
+   * Monsters with unknown values are loaded anyway and only match monsters with the same value.
+   */
+  load_warnings(): CsvWarning[];
+  /**
+   * Search the copies of the candidate monsters to add to the main deck of the core,
+   * at most `config.budget` cards, that maximize the mean chance to reach each target.
+   * Candidates that are not in the database are ignored.
+   */
+  optimize_deck(core: Deck, candidate_ids: Uint32Array, target_ids: Uint32Array, config: OptimizerConfig): OptimizedDeck;
+  unknown_stats(): UnknownStats;
+  /**
+   * Greedily select up to k bridges to add to the deck. Each step picks the monster
+   * connecting the most pairs not covered by the deck or a previously selected bridge,
+   * so `new_connections` is the marginal gain of every pick.
+   */
+  select_bridges(deck_ids: Uint32Array, k: number): Recommendations;
+  connection_rule(): ConnectionRule;
+  /**
+   * List every reveal -> bridge -> target line of a concrete hand. Bridge and target have to be
+   * cards left in the main deck of `deck`, so the same passcode can only be both with two copies.
+   */
+  find_hand_lines(hand_ids: Uint32Array, deck: Deck): HandLines;
+  /**
+   * Replace the passcodes of Spells, Traps and Extra Deck monsters loaded with the card database,
+   * so diagnostics can tell them apart from passcodes that are not in the database at all.
+   */
+  set_other_cards(ids: Uint32Array): void;
+  /**
+   * Like the constructor, but rows that can not be parsed are skipped and
+   * reported in `load_warnings` instead of failing.
+   */
+  static from_csv_lenient(data: string): SmallWorldSearcher;
+  /**
+   * Rank monsters outside the deck by how many new hand/target pairs of the deck
+   * they would connect if added as a bridge. Pairs are unordered and only pairs
+   * that can not already be connected through a bridge in the deck are counted.
+   */
+  recommend_bridges(deck_ids: Uint32Array, limit: number): Recommendations;
+  /**
+   * Replace how unknown ("?") ATK and DEF are compared, all following queries use it.
+   */
+  set_unknown_stats(unknown: UnknownStats): void;
+  /**
+   * Estimate the opening odds by shuffling the main deck `trials` times, taking into account
+   * that bridges and targets drawn into the hand are no longer in the deck.
+   * The same seed always gives the same result.
+   */
+  simulate_openings(deck: Deck, hand_size: number, target_ids: Uint32Array, trials: number, seed: bigint): SimulationResult;
+  /**
+   * Graph metrics of the deck graph built by `compute_links_within`.
+   */
+  compute_deck_stats(pool_ids: Uint32Array): DeckGraphStats;
   /**
    * Find monsters that connect every source monster to every target monster.
    */
-  find_common_bridges(source: Uint32Array, target: Uint32Array): Monster[] | undefined;
+  find_common_bridges(source: Uint32Array, target: Uint32Array): Bridges;
+  /**
+   * Replace the rule that decides when two monsters are connected, all following queries use it.
+   */
+  set_connection_rule(rule: ConnectionRule): void;
   /**
    * For every monster in the pool, find to which other monsters from the pool it can link to, excluding self-links.
    */
-  compute_links_within(pool_ids: Uint32Array): Link[];
-  find_universal_bridges(ids: Uint32Array): Monster[] | undefined;
+  compute_links_within(pool_ids: Uint32Array): Links;
+  /**
+   * Precompute the neighborhood of every monster, all following queries look them up
+   * instead of recomputing them. Takes a few MB for the full card pool.
+   */
+  precompute_adjacency(): void;
+  find_universal_bridges(ids: Uint32Array): Bridges;
+  is_adjacency_precomputed(): boolean;
+  /**
+   * Like `compute_links_within`, but symmetric links are merged and grouped by source/target pair.
+   * Each unordered pair appears once, with the monster that comes first in the card pool as source.
+   */
+  compute_link_groups_within(pool_ids: Uint32Array): LinkGroups;
   /**
    * Given two monsters m1 and m2, lookup the first property that connects them.
-   * Returns a String or None if they are not connected.
+   * Returns a String or None if they are not connected, see `diagnose` for unknown passcodes.
    */
   compute_connecting_property(m1: number, m2: number): string | undefined;
+  /**
+   * Every monster of the card pool that matches the filter.
+   */
+  search(filter: CardFilter): Monster[];
+  aliases(): AliasTable;
   get_all(): Monster[];
+  /**
+   * Classify the passcodes that would be ignored or resolved as alias by a query,
+   * e.g. to explain why a single card lookup returned nothing.
+   */
+  diagnose(ids: Uint32Array): Diagnostics;
   constructor(data: string);
   get_by_id(id: number): Monster | undefined;
+  /**
+   * The schema version of the loaded card database, older than `csv_schema_version()`
+   * if it was cached before the schema changed.
+   */
+  readonly schema_version: number;
 }
 
-export function decode_ydke(ydke: string, ignore_extra: boolean): Uint32Array;
+export class Source {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  readonly source: number;
+  readonly bridges: Uint32Array;
+}
+
+export class Sources {
+  private constructor();
+  free(): void;
+  [Symbol.dispose](): void;
+  /**
+   * passcodes of the query that were ignored or resolved as alias
+   */
+  readonly diagnostics: Diagnostics;
+  readonly sources: Source[];
+}
+
+/**
+ * How unknown ("?") ATK and DEF values are compared.
+ */
+export enum UnknownStats {
+  /**
+   * "?" counts as 0, the ruling for monsters in the Deck
+   */
+  AsZero = 0,
+  /**
+   * "?" never shares the stat with any monster
+   */
+  NeverMatch = 1,
+  /**
+   * "?" shares the stat with every other "?"
+   */
+  MatchEachOther = 2,
+}
+
+/**
+ * The CSV schema version of this build, a card database with an older version is stale
+ */
+export function csv_schema_version(): number;
+
+/**
+ * Decode a YDKE string, with `ignore_extra` the Extra Deck is left empty
+ */
+export function decode_ydke(ydke: string, ignore_extra: boolean): Deck;
+
+/**
+ * Decode a YDKE string, alternate artworks are replaced by their canonical passcode
+ */
+export function decode_ydke_with_aliases(ydke: string, aliases: AliasTable): Deck;
+
+/**
+ * Encode all sections of the deck into a YDKE string
+ */
+export function encode_ydke(deck: Deck): string;
 
 /**
  * Encode into a YDKE main deck string
  */
 export function encode_ydke_main(ids: Uint32Array): string;
 
-export function parse_ydk(ydk: string, ignore_extra: boolean): Uint32Array;
+/**
+ * Parse a YDK file, cards are sorted into the sections by the `#main`, `#extra` and `!side` headers.
+ * With `ignore_extra` the Extra Deck is left empty.
+ */
+export function parse_ydk(ydk: string, ignore_extra: boolean): Deck;
+
+/**
+ * Parse a YDK file, alternate artworks are replaced by their canonical passcode
+ */
+export function parse_ydk_with_aliases(ydk: string, aliases: AliasTable): Deck;
 
 export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembly.Module;
 
 export interface InitOutput {
   readonly memory: WebAssembly.Memory;
+  readonly __wbg_bridges_free: (a: number, b: number) => void;
+  readonly __wbg_chain_free: (a: number, b: number) => void;
+  readonly __wbg_chains_free: (a: number, b: number) => void;
+  readonly __wbg_deckgraphstats_free: (a: number, b: number) => void;
+  readonly __wbg_filteredids_free: (a: number, b: number) => void;
+  readonly __wbg_handlines_free: (a: number, b: number) => void;
+  readonly __wbg_hop_free: (a: number, b: number) => void;
   readonly __wbg_link_free: (a: number, b: number) => void;
+  readonly __wbg_linkgroups_free: (a: number, b: number) => void;
+  readonly __wbg_links_free: (a: number, b: number) => void;
+  readonly __wbg_pathexplanation_free: (a: number, b: number) => void;
+  readonly __wbg_pathgroup_free: (a: number, b: number) => void;
+  readonly __wbg_pathgroups_free: (a: number, b: number) => void;
+  readonly __wbg_recommendation_free: (a: number, b: number) => void;
+  readonly __wbg_recommendations_free: (a: number, b: number) => void;
   readonly __wbg_smallworldsearcher_free: (a: number, b: number) => void;
+  readonly __wbg_source_free: (a: number, b: number) => void;
+  readonly __wbg_sources_free: (a: number, b: number) => void;
+  readonly bridges_diagnostics: (a: number) => number;
+  readonly bridges_monsters: (a: number) => [number, number];
+  readonly chain_activations: (a: number) => number;
+  readonly chain_path: (a: number) => [number, number];
+  readonly chain_target: (a: number) => number;
+  readonly chains_chains: (a: number) => [number, number];
+  readonly chains_diagnostics: (a: number) => number;
+  readonly deckgraphstats_bridge_share: (a: number) => [number, number];
+  readonly deckgraphstats_bridges: (a: number) => [number, number];
+  readonly deckgraphstats_component_count: (a: number) => number;
+  readonly deckgraphstats_components: (a: number) => [number, number];
+  readonly deckgraphstats_critical_bridges: (a: number) => [number, number];
+  readonly deckgraphstats_critical_pairs: (a: number) => [number, number];
+  readonly deckgraphstats_dead_ends: (a: number) => [number, number];
+  readonly deckgraphstats_degrees: (a: number) => [number, number];
+  readonly deckgraphstats_diagnostics: (a: number) => number;
+  readonly deckgraphstats_ids: (a: number) => [number, number];
+  readonly deckgraphstats_unreachable: (a: number) => [number, number];
+  readonly filteredids_diagnostics: (a: number) => number;
+  readonly filteredids_ids: (a: number) => [number, number];
+  readonly handlines_diagnostics: (a: number) => number;
+  readonly handlines_has_small_world: (a: number) => number;
+  readonly handlines_lines: (a: number) => [number, number];
+  readonly hop_properties: (a: number) => [number, number];
+  readonly hop_values: (a: number) => [number, number];
   readonly link_bridge: (a: number) => number;
   readonly link_start: (a: number) => number;
   readonly link_target: (a: number) => number;
+  readonly linkgroups_bridge_offsets: (a: number) => [number, number];
+  readonly linkgroups_bridges: (a: number) => [number, number];
+  readonly linkgroups_diagnostics: (a: number) => number;
+  readonly linkgroups_is_empty: (a: number) => number;
+  readonly linkgroups_len: (a: number) => number;
+  readonly linkgroups_sources: (a: number) => [number, number];
+  readonly linkgroups_targets: (a: number) => [number, number];
+  readonly links_diagnostics: (a: number) => number;
+  readonly links_links: (a: number) => [number, number];
+  readonly pathexplanation_bridge: (a: number) => number;
+  readonly pathexplanation_bridge_to_target: (a: number) => number;
+  readonly pathexplanation_hand: (a: number) => number;
+  readonly pathexplanation_hand_to_bridge: (a: number) => number;
+  readonly pathexplanation_target: (a: number) => number;
+  readonly pathgroup_paths: (a: number) => [number, number];
+  readonly pathgroups_diagnostics: (a: number) => number;
+  readonly pathgroups_groups: (a: number) => [number, number];
+  readonly recommendation_connects: (a: number) => [number, number];
+  readonly recommendations_diagnostics: (a: number) => number;
+  readonly recommendations_recommendations: (a: number) => [number, number];
+  readonly smallworldsearcher_aliases: (a: number) => number;
+  readonly smallworldsearcher_canonical_id: (a: number, b: number) => number;
   readonly smallworldsearcher_compute_connecting_property: (a: number, b: number, c: number) => [number, number];
-  readonly smallworldsearcher_compute_links_within: (a: number, b: number, c: number) => [number, number];
-  readonly smallworldsearcher_find_common_bridges: (a: number, b: number, c: number, d: number, e: number) => [number, number];
-  readonly smallworldsearcher_find_universal_bridges: (a: number, b: number, c: number) => [number, number];
-  readonly smallworldsearcher_from_csv: (a: number, b: number) => number;
+  readonly smallworldsearcher_compute_deck_stats: (a: number, b: number, c: number) => number;
+  readonly smallworldsearcher_compute_link_groups_within: (a: number, b: number, c: number) => number;
+  readonly smallworldsearcher_compute_links_within: (a: number, b: number, c: number) => number;
+  readonly smallworldsearcher_connection_rule: (a: number) => number;
+  readonly smallworldsearcher_diagnose: (a: number, b: number, c: number) => number;
+  readonly smallworldsearcher_explain_path: (a: number, b: number, c: number, d: number) => number;
+  readonly smallworldsearcher_filter_ids: (a: number, b: number, c: number, d: number) => number;
+  readonly smallworldsearcher_find_chains: (a: number, b: number, c: number, d: number, e: number) => number;
+  readonly smallworldsearcher_find_common_bridges: (a: number, b: number, c: number, d: number, e: number) => number;
+  readonly smallworldsearcher_find_hand_lines: (a: number, b: number, c: number, d: number) => number;
+  readonly smallworldsearcher_find_paths: (a: number, b: number, c: number, d: number, e: number) => number;
+  readonly smallworldsearcher_find_sources: (a: number, b: number, c: number, d: number) => number;
+  readonly smallworldsearcher_find_universal_bridges: (a: number, b: number, c: number) => number;
+  readonly smallworldsearcher_from_csv: (a: number, b: number) => [number, number, number];
+  readonly smallworldsearcher_from_csv_lenient: (a: number, b: number) => [number, number, number];
   readonly smallworldsearcher_get_all: (a: number) => [number, number];
   readonly smallworldsearcher_get_by_id: (a: number, b: number) => number;
-  readonly decode_ydke: (a: number, b: number, c: number) => [number, number];
-  readonly encode_ydke_main: (a: number, b: number) => [number, number];
-  readonly parse_ydk: (a: number, b: number, c: number) => [number, number];
+  readonly smallworldsearcher_is_adjacency_precomputed: (a: number) => number;
+  readonly smallworldsearcher_load_warnings: (a: number) => [number, number];
+  readonly smallworldsearcher_opening_odds: (a: number, b: number, c: number, d: number, e: number) => number;
+  readonly smallworldsearcher_optimize_deck: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => number;
+  readonly smallworldsearcher_parse_filter: (a: number, b: number, c: number) => [number, number, number];
+  readonly smallworldsearcher_precompute_adjacency: (a: number) => void;
+  readonly smallworldsearcher_recommend_bridges: (a: number, b: number, c: number, d: number) => number;
+  readonly smallworldsearcher_resolve_name: (a: number, b: number, c: number) => number;
+  readonly smallworldsearcher_schema_version: (a: number) => number;
+  readonly smallworldsearcher_search: (a: number, b: number) => [number, number];
+  readonly smallworldsearcher_search_names: (a: number, b: number, c: number, d: number) => [number, number];
+  readonly smallworldsearcher_select_bridges: (a: number, b: number, c: number, d: number) => number;
+  readonly smallworldsearcher_set_aliases: (a: number, b: number) => void;
+  readonly smallworldsearcher_set_connection_rule: (a: number, b: number) => void;
+  readonly smallworldsearcher_set_other_cards: (a: number, b: number, c: number) => void;
+  readonly smallworldsearcher_set_unknown_stats: (a: number, b: number) => void;
+  readonly smallworldsearcher_simulate_openings: (a: number, b: number, c: number, d: number, e: number, f: number, g: bigint) => number;
+  readonly smallworldsearcher_unknown_stats: (a: number) => number;
+  readonly source_bridges: (a: number) => [number, number];
+  readonly sources_diagnostics: (a: number) => number;
+  readonly sources_sources: (a: number) => [number, number];
+  readonly pathgroup_hand: (a: number) => number;
+  readonly pathgroup_target: (a: number) => number;
+  readonly recommendation_bridge: (a: number) => number;
+  readonly recommendation_new_connections: (a: number) => number;
+  readonly source_source: (a: number) => number;
+  readonly __wbg_connectionrule_free: (a: number, b: number) => void;
+  readonly __wbg_openingodds_free: (a: number, b: number) => void;
+  readonly connectionrule_at_least: (a: number, b: number, c: number) => number;
+  readonly connectionrule_at_most: (a: number, b: number, c: number) => number;
+  readonly connectionrule_exactly: (a: number, b: number, c: number) => number;
+  readonly connectionrule_small_world: () => number;
+  readonly openingodds_any_target: (a: number) => number;
+  readonly openingodds_diagnostics: (a: number) => number;
+  readonly openingodds_per_target: (a: number) => [number, number];
+  readonly openingodds_small_world: (a: number) => number;
+  readonly openingodds_targets: (a: number) => [number, number];
+  readonly __wbg_diagnostics_free: (a: number, b: number) => void;
+  readonly __wbg_ignoredid_free: (a: number, b: number) => void;
   readonly __wbg_monster_free: (a: number, b: number) => void;
-  readonly monster_atk: (a: number) => number;
+  readonly diagnostics_dropped_ids: (a: number) => [number, number];
+  readonly diagnostics_ignored: (a: number) => [number, number];
+  readonly diagnostics_is_empty: (a: number) => number;
+  readonly ignoredid_canonical: (a: number) => number;
+  readonly ignoredid_id: (a: number) => number;
+  readonly ignoredid_message: (a: number) => [number, number];
+  readonly ignoredid_reason: (a: number) => number;
+  readonly monster_abilities_js: (a: number) => [number, number];
+  readonly monster_archetype: (a: number) => [number, number];
   readonly monster_attribute_js: (a: number) => [number, number];
+  readonly monster_ban_status: (a: number) => number;
   readonly monster_def: (a: number) => number;
+  readonly monster_frame: (a: number) => number;
   readonly monster_id: (a: number) => number;
   readonly monster_level: (a: number) => number;
   readonly monster_name_js: (a: number) => [number, number];
+  readonly monster_release_date: (a: number) => [number, number];
+  readonly monster_scale: (a: number) => number;
   readonly monster_type_js: (a: number) => [number, number];
+  readonly monster_atk: (a: number) => number;
+  readonly __wbg_aliastable_free: (a: number, b: number) => void;
+  readonly aliastable_canonical: (a: number, b: number) => number;
+  readonly aliastable_canonical_deck: (a: number, b: number) => number;
+  readonly aliastable_from_csv: (a: number, b: number) => [number, number, number];
+  readonly aliastable_is_alias: (a: number, b: number) => number;
+  readonly aliastable_is_empty: (a: number) => number;
+  readonly aliastable_len: (a: number) => number;
+  readonly aliastable_new: () => number;
+  readonly __wbg_csvwarning_free: (a: number, b: number) => void;
+  readonly csv_schema_version: () => number;
+  readonly csvwarning_column: (a: number) => [number, number];
+  readonly csvwarning_message: (a: number) => [number, number];
+  readonly csvwarning_row: (a: number) => number;
+  readonly csvwarning_skipped: (a: number) => number;
+  readonly csvwarning_value: (a: number) => [number, number];
+  readonly decode_ydke: (a: number, b: number, c: number) => [number, number, number];
+  readonly decode_ydke_with_aliases: (a: number, b: number, c: number) => [number, number, number];
+  readonly encode_ydke: (a: number) => [number, number];
+  readonly encode_ydke_main: (a: number, b: number) => [number, number];
+  readonly parse_ydk: (a: number, b: number, c: number) => number;
+  readonly parse_ydk_with_aliases: (a: number, b: number, c: number) => number;
+  readonly __wbg_deck_free: (a: number, b: number) => void;
+  readonly __wbg_deckchange_free: (a: number, b: number) => void;
+  readonly __wbg_deckissue_free: (a: number, b: number) => void;
+  readonly __wbg_gamestate_free: (a: number, b: number) => void;
+  readonly __wbg_get_optimizerconfig_budget: (a: number) => number;
+  readonly __wbg_get_optimizerconfig_hand_size: (a: number) => number;
+  readonly __wbg_get_optimizerconfig_iterations: (a: number) => number;
+  readonly __wbg_get_optimizerconfig_seed: (a: number) => bigint;
+  readonly __wbg_get_optimizerconfig_strategy: (a: number) => number;
+  readonly __wbg_optimizeddeck_free: (a: number, b: number) => void;
+  readonly __wbg_optimizerconfig_free: (a: number, b: number) => void;
+  readonly __wbg_set_optimizerconfig_budget: (a: number, b: number) => void;
+  readonly __wbg_set_optimizerconfig_hand_size: (a: number, b: number) => void;
+  readonly __wbg_set_optimizerconfig_iterations: (a: number, b: number) => void;
+  readonly __wbg_set_optimizerconfig_seed: (a: number, b: bigint) => void;
+  readonly __wbg_set_optimizerconfig_strategy: (a: number, b: number) => void;
+  readonly deck_add: (a: number, b: number, c: number, d: number) => void;
+  readonly deck_copies: (a: number, b: number) => number;
+  readonly deck_count: (a: number, b: number, c: number) => number;
+  readonly deck_counts: (a: number, b: number) => [number, number];
+  readonly deck_diff: (a: number, b: number) => [number, number];
+  readonly deck_ids: (a: number, b: number) => [number, number];
+  readonly deck_is_empty: (a: number) => number;
+  readonly deck_len: (a: number, b: number) => number;
+  readonly deck_new: () => number;
+  readonly deck_remove: (a: number, b: number, c: number, d: number) => number;
+  readonly deck_unique_ids: (a: number, b: number) => [number, number];
+  readonly deck_validate: (a: number) => [number, number];
+  readonly deckchange_from: (a: number) => number;
+  readonly deckchange_id: (a: number) => number;
+  readonly deckchange_section: (a: number) => number;
+  readonly deckchange_to: (a: number) => number;
+  readonly deckissue_id: (a: number) => number;
+  readonly deckissue_kind: (a: number) => number;
+  readonly deckissue_message: (a: number) => [number, number];
+  readonly gamestate_apply_small_world: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+  readonly gamestate_banished: (a: number) => [number, number];
+  readonly gamestate_check_small_world: (a: number, b: number, c: number, d: number, e: number) => number;
+  readonly gamestate_deck: (a: number) => number;
+  readonly gamestate_draw: (a: number, b: number) => number;
+  readonly gamestate_graveyard: (a: number) => [number, number];
+  readonly gamestate_hand: (a: number) => [number, number];
+  readonly gamestate_lines: (a: number, b: number) => number;
+  readonly gamestate_new: (a: number, b: number, c: number, d: number) => number;
+  readonly optimizeddeck_coverage: (a: number) => number;
+  readonly optimizeddeck_deck: (a: number) => number;
+  readonly optimizeddeck_diagnostics: (a: number) => number;
+  readonly optimizeddeck_evaluations: (a: number) => number;
+  readonly optimizerconfig_new: (a: number, b: number) => number;
+  readonly deckissue_count: (a: number) => number;
+  readonly __wbg_cardfilter_free: (a: number, b: number) => void;
+  readonly __wbg_filtererror_free: (a: number, b: number) => void;
+  readonly cardfilter_is_empty: (a: number) => number;
+  readonly cardfilter_parse: (a: number, b: number) => [number, number, number];
+  readonly filtererror_message: (a: number) => [number, number];
+  readonly filtererror_position: (a: number) => number;
+  readonly __wbg_simulationresult_free: (a: number, b: number) => void;
+  readonly simulationresult_any_target: (a: number) => number;
+  readonly simulationresult_diagnostics: (a: number) => number;
+  readonly simulationresult_lower_bounds: (a: number) => [number, number];
+  readonly simulationresult_rates: (a: number) => [number, number];
+  readonly simulationresult_small_world: (a: number) => number;
+  readonly simulationresult_successes: (a: number) => [number, number];
+  readonly simulationresult_targets: (a: number) => [number, number];
+  readonly simulationresult_trials: (a: number) => number;
+  readonly simulationresult_upper_bounds: (a: number) => [number, number];
   readonly __wbindgen_externrefs: WebAssembly.Table;
-  readonly __wbindgen_malloc: (a: number, b: number) => number;
-  readonly __externref_drop_slice: (a: number, b: number) => void;
   readonly __wbindgen_free: (a: number, b: number, c: number) => void;
+  readonly __externref_drop_slice: (a: number, b: number) => void;
+  readonly __wbindgen_malloc: (a: number, b: number) => number;
   readonly __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+  readonly __externref_table_dealloc: (a: number) => void;
+  readonly __externref_table_alloc: () => number;
   readonly __wbindgen_start: () => void;
 }
 
@@ -1,5 +1,22 @@
 let wasm;
 
+function addToExternrefTable0(obj) {
+    const idx = wasm.__externref_table_alloc();
+    wasm.__wbindgen_externrefs.set(idx, obj);
+    return idx;
+}
+
+function _assertClass(instance, klass) {
+    if (!(instance instanceof klass)) {
+        throw new Error(`expected instance of ${klass.name}`);
+    }
+}
+
+function getArrayF64FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getFloat64ArrayMemory0().subarray(ptr / 8, ptr / 8 + len);
+}
+
 function getArrayJsValueFromWasm0(ptr, len) {
     ptr = ptr >>> 0;
     const mem = getDataViewMemory0();
@@ -24,6 +41,14 @@ function getDataViewMemory0() {
     return cachedDataViewMemory0;
 }
 
+let cachedFloat64ArrayMemory0 = null;
+function getFloat64ArrayMemory0() {
+    if (cachedFloat64ArrayMemory0 === null || cachedFloat64ArrayMemory0.byteLength === 0) {
+        cachedFloat64ArrayMemory0 = new Float64Array(wasm.memory.buffer);
+    }
+    return cachedFloat64ArrayMemory0;
+}
+
 function getStringFromWasm0(ptr, len) {
     ptr = ptr >>> 0;
     return decodeText(ptr, len);
@@ -45,6 +70,10 @@ function getUint8ArrayMemory0() {
     return cachedUint8ArrayMemory0;
 }
 
+function isLikeNone(x) {
+    return x === undefined || x === null;
+}
+
 function passArray32ToWasm0(arg, malloc) {
     const ptr = malloc(arg.length * 4, 4) >>> 0;
     getUint32ArrayMemory0().set(arg, ptr / 4);
@@ -52,6 +81,16 @@ function passArray32ToWasm0(arg, malloc) {
     return ptr;
 }
 
+function passArrayJsValueToWasm0(array, malloc) {
+    const ptr = malloc(array.length * 4, 4) >>> 0;
+    for (let i = 0; i < array.length; i++) {
+        const add = addToExternrefTable0(array[i]);
+        getDataViewMemory0().setUint32(ptr + 4 * i, add, true);
+    }
+    WASM_VECTOR_LEN = array.length;
+    return ptr;
+}
+
 function passStringToWasm0(arg, malloc, realloc) {
     if (realloc === undefined) {
         const buf = cachedTextEncoder.encode(arg);
@@ -89,6 +128,12 @@ function passStringToWasm0(arg, malloc, realloc) {
     return ptr;
 }
 
+function takeFromExternrefTable0(idx) {
+    const value = wasm.__wbindgen_externrefs.get(idx);
+    wasm.__externref_table_dealloc(idx);
+    return value;
+}
+
 let cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });
 cachedTextDecoder.decode();
 const MAX_SAFARI_DECODE_BYTES = 2146435072;
@@ -118,215 +163,2743 @@ if (!('encodeInto' in cachedTextEncoder)) {
 
 let WASM_VECTOR_LEN = 0;
 
+const AliasTableFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_aliastable_free(ptr >>> 0, 1));
+
+const BridgesFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_bridges_free(ptr >>> 0, 1));
+
+const CardFilterFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_cardfilter_free(ptr >>> 0, 1));
+
+const ChainFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_chain_free(ptr >>> 0, 1));
+
+const ChainsFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_chains_free(ptr >>> 0, 1));
+
+const ConnectionRuleFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_connectionrule_free(ptr >>> 0, 1));
+
+const CsvWarningFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_csvwarning_free(ptr >>> 0, 1));
+
+const DeckFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_deck_free(ptr >>> 0, 1));
+
+const DeckChangeFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_deckchange_free(ptr >>> 0, 1));
+
+const DeckGraphStatsFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_deckgraphstats_free(ptr >>> 0, 1));
+
+const DeckIssueFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_deckissue_free(ptr >>> 0, 1));
+
+const DiagnosticsFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_diagnostics_free(ptr >>> 0, 1));
+
+const FilterErrorFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_filtererror_free(ptr >>> 0, 1));
+
+const FilteredIdsFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_filteredids_free(ptr >>> 0, 1));
+
+const GameStateFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_gamestate_free(ptr >>> 0, 1));
+
+const HandLinesFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_handlines_free(ptr >>> 0, 1));
+
+const HopFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_hop_free(ptr >>> 0, 1));
+
+const IgnoredIdFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_ignoredid_free(ptr >>> 0, 1));
+
 const LinkFinalization = (typeof FinalizationRegistry === 'undefined')
     ? { register: () => {}, unregister: () => {} }
     : new FinalizationRegistry(ptr => wasm.__wbg_link_free(ptr >>> 0, 1));
 
+const LinkGroupsFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_linkgroups_free(ptr >>> 0, 1));
+
+const LinksFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_links_free(ptr >>> 0, 1));
+
 const MonsterFinalization = (typeof FinalizationRegistry === 'undefined')
     ? { register: () => {}, unregister: () => {} }
     : new FinalizationRegistry(ptr => wasm.__wbg_monster_free(ptr >>> 0, 1));
 
+const OpeningOddsFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_openingodds_free(ptr >>> 0, 1));
+
+const OptimizedDeckFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_optimizeddeck_free(ptr >>> 0, 1));
+
+const OptimizerConfigFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_optimizerconfig_free(ptr >>> 0, 1));
+
+const PathExplanationFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_pathexplanation_free(ptr >>> 0, 1));
+
+const PathGroupFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_pathgroup_free(ptr >>> 0, 1));
+
+const PathGroupsFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_pathgroups_free(ptr >>> 0, 1));
+
+const RecommendationFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_recommendation_free(ptr >>> 0, 1));
+
+const RecommendationsFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_recommendations_free(ptr >>> 0, 1));
+
+const SimulationResultFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_simulationresult_free(ptr >>> 0, 1));
+
 const SmallWorldSearcherFinalization = (typeof FinalizationRegistry === 'undefined')
     ? { register: () => {}, unregister: () => {} }
     : new FinalizationRegistry(ptr => wasm.__wbg_smallworldsearcher_free(ptr >>> 0, 1));
 
-export class Link {
+const SourceFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_source_free(ptr >>> 0, 1));
+
+const SourcesFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_sources_free(ptr >>> 0, 1));
+
+/**
+ * Monster abilities printed next to the type, e.g. "Spellcaster/Tuner".
+ * @enum {0 | 1 | 2 | 3 | 4 | 5}
+ */
+export const Ability = Object.freeze({
+    Tuner: 0, "0": "Tuner",
+    Flip: 1, "1": "Flip",
+    Gemini: 2, "2": "Gemini",
+    Spirit: 3, "3": "Spirit",
+    Toon: 4, "4": "Toon",
+    Union: 5, "5": "Union",
+});
+
+/**
+ * Why a Small World activation can not be resolved.
+ * @enum {0 | 1 | 2 | 3 | 4 | 5 | 6}
+ */
+export const ActivationError = Object.freeze({
+    NoSmallWorld: 0, "0": "NoSmallWorld",
+    RevealNotInHand: 1, "1": "RevealNotInHand",
+    BridgeNotInDeck: 2, "2": "BridgeNotInDeck",
+    TargetNotInDeck: 3, "3": "TargetNotInDeck",
+    NotAMonster: 4, "4": "NotAMonster",
+    BridgeNotConnected: 5, "5": "BridgeNotConnected",
+    TargetNotConnected: 6, "6": "TargetNotConnected",
+});
+
+/**
+ * Maps the passcodes of alternate artworks to the passcode used in the card database.
+ */
+export class AliasTable {
     static __wrap(ptr) {
         ptr = ptr >>> 0;
-        const obj = Object.create(Link.prototype);
+        const obj = Object.create(AliasTable.prototype);
         obj.__wbg_ptr = ptr;
-        LinkFinalization.register(obj, obj.__wbg_ptr, obj);
+        AliasTableFinalization.register(obj, obj.__wbg_ptr, obj);
         return obj;
     }
     __destroy_into_raw() {
         const ptr = this.__wbg_ptr;
         this.__wbg_ptr = 0;
-        LinkFinalization.unregister(this);
+        AliasTableFinalization.unregister(this);
         return ptr;
     }
     free() {
         const ptr = this.__destroy_into_raw();
-        wasm.__wbg_link_free(ptr, 0);
+        wasm.__wbg_aliastable_free(ptr, 0);
     }
     /**
-     * @returns {Monster}
+     * A copy of the deck with every passcode replaced by its canonical one,
+     * copies of different artworks are merged.
+     * @param {Deck} deck
+     * @returns {Deck}
      */
-    get start() {
-        const ret = wasm.link_start(this.__wbg_ptr);
-        return Monster.__wrap(ret);
+    canonical_deck(deck) {
+        _assertClass(deck, Deck);
+        const ret = wasm.aliastable_canonical_deck(this.__wbg_ptr, deck.__wbg_ptr);
+        return Deck.__wrap(ret);
     }
     /**
-     * @returns {Monster}
+     * @returns {number}
      */
-    get bridge() {
-        const ret = wasm.link_bridge(this.__wbg_ptr);
-        return Monster.__wrap(ret);
+    len() {
+        const ret = wasm.aliastable_len(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    constructor() {
+        const ret = wasm.aliastable_new();
+        this.__wbg_ptr = ret >>> 0;
+        AliasTableFinalization.register(this, this.__wbg_ptr, this);
+        return this;
     }
     /**
-     * @returns {Monster}
+     * Parse a CSV file with the columns `alias,id`, e.g. for a card database without aliases.
+     * @param {string} data
+     * @returns {AliasTable}
      */
-    get target() {
-        const ret = wasm.link_target(this.__wbg_ptr);
-        return Monster.__wrap(ret);
+    static from_csv(data) {
+        const ptr0 = passStringToWasm0(data, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.aliastable_from_csv(ptr0, len0);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return AliasTable.__wrap(ret[0]);
+    }
+    /**
+     * @param {number} id
+     * @returns {boolean}
+     */
+    is_alias(id) {
+        const ret = wasm.aliastable_is_alias(this.__wbg_ptr, id);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    is_empty() {
+        const ret = wasm.aliastable_is_empty(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * The canonical passcode, passcodes without alias are returned unchanged.
+     * @param {number} id
+     * @returns {number}
+     */
+    canonical(id) {
+        const ret = wasm.aliastable_canonical(this.__wbg_ptr, id);
+        return ret >>> 0;
     }
 }
-if (Symbol.dispose) Link.prototype[Symbol.dispose] = Link.prototype.free;
+if (Symbol.dispose) AliasTable.prototype[Symbol.dispose] = AliasTable.prototype.free;
 
-export class Monster {
+/**
+ * The status of a card on the banlist.
+ * @enum {0 | 1 | 2 | 3}
+ */
+export const BanStatus = Object.freeze({
+    Forbidden: 0, "0": "Forbidden",
+    Limited: 1, "1": "Limited",
+    SemiLimited: 2, "2": "SemiLimited",
+    Unlimited: 3, "3": "Unlimited",
+});
+
+/**
+ * The bridges found for the monsters of a query, empty if there are none.
+ */
+export class Bridges {
     static __wrap(ptr) {
         ptr = ptr >>> 0;
-        const obj = Object.create(Monster.prototype);
+        const obj = Object.create(Bridges.prototype);
         obj.__wbg_ptr = ptr;
-        MonsterFinalization.register(obj, obj.__wbg_ptr, obj);
+        BridgesFinalization.register(obj, obj.__wbg_ptr, obj);
         return obj;
     }
     __destroy_into_raw() {
         const ptr = this.__wbg_ptr;
         this.__wbg_ptr = 0;
-        MonsterFinalization.unregister(this);
+        BridgesFinalization.unregister(this);
         return ptr;
     }
     free() {
         const ptr = this.__destroy_into_raw();
-        wasm.__wbg_monster_free(ptr, 0);
+        wasm.__wbg_bridges_free(ptr, 0);
     }
     /**
-     * @returns {string}
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
      */
-    get attribute_js() {
-        let deferred1_0;
-        let deferred1_1;
-        try {
-            const ret = wasm.monster_attribute_js(this.__wbg_ptr);
-            deferred1_0 = ret[0];
-            deferred1_1 = ret[1];
-            return getStringFromWasm0(ret[0], ret[1]);
-        } finally {
-            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
-        }
+    get diagnostics() {
+        const ret = wasm.bridges_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
     }
     /**
-     * @returns {number}
+     * @returns {Monster[]}
      */
-    get id() {
-        const ret = wasm.monster_id(this.__wbg_ptr);
-        return ret >>> 0;
+    get monsters() {
+        const ret = wasm.bridges_monsters(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) Bridges.prototype[Symbol.dispose] = Bridges.prototype.free;
+
+/**
+ * A parsed filter expression, see the module documentation for the syntax.
+ */
+export class CardFilter {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(CardFilter.prototype);
+        obj.__wbg_ptr = ptr;
+        CardFilterFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        CardFilterFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_cardfilter_free(ptr, 0);
     }
     /**
-     * @returns {number | undefined}
+     * @param {string} query
+     * @returns {CardFilter}
      */
-    get atk() {
-        const ret = wasm.monster_atk(this.__wbg_ptr);
-        return ret === 0x100000001 ? undefined : ret;
+    static parse(query) {
+        const ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.cardfilter_parse(ptr0, len0);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return CardFilter.__wrap(ret[0]);
     }
     /**
-     * @returns {number | undefined}
+     * whether the filter has no terms and matches every monster
+     * @returns {boolean}
      */
-    get def() {
-        const ret = wasm.monster_def(this.__wbg_ptr);
-        return ret === 0x100000001 ? undefined : ret;
+    is_empty() {
+        const ret = wasm.cardfilter_is_empty(this.__wbg_ptr);
+        return ret !== 0;
+    }
+}
+if (Symbol.dispose) CardFilter.prototype[Symbol.dispose] = CardFilter.prototype.free;
+
+/**
+ * A target reached by consecutive Small World activations.
+ */
+export class Chain {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Chain.prototype);
+        obj.__wbg_ptr = ptr;
+        ChainFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        ChainFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_chain_free(ptr, 0);
     }
     /**
+     * minimum number of Small World activations needed to reach the target
      * @returns {number}
      */
-    get level() {
-        const ret = wasm.monster_level(this.__wbg_ptr);
+    get activations() {
+        const ret = wasm.chain_activations(this.__wbg_ptr);
         return ret >>> 0;
     }
     /**
-     * @returns {string}
+     * hand, bridge, target of the 1st activation, bridge, target of the 2nd activation, ...
+     * @returns {Uint32Array}
      */
-    get name_js() {
-        let deferred1_0;
-        let deferred1_1;
-        try {
-            const ret = wasm.monster_name_js(this.__wbg_ptr);
-            deferred1_0 = ret[0];
-            deferred1_1 = ret[1];
-            return getStringFromWasm0(ret[0], ret[1]);
-        } finally {
-            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
-        }
+    get path() {
+        const ret = wasm.chain_path(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
     }
     /**
-     * @returns {string}
+     * @returns {number}
      */
-    get type_js() {
-        let deferred1_0;
-        let deferred1_1;
-        try {
-            const ret = wasm.monster_type_js(this.__wbg_ptr);
-            deferred1_0 = ret[0];
-            deferred1_1 = ret[1];
-            return getStringFromWasm0(ret[0], ret[1]);
-        } finally {
-            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
-        }
+    get target() {
+        const ret = wasm.chain_target(this.__wbg_ptr);
+        return ret >>> 0;
     }
 }
-if (Symbol.dispose) Monster.prototype[Symbol.dispose] = Monster.prototype.free;
+if (Symbol.dispose) Chain.prototype[Symbol.dispose] = Chain.prototype.free;
 
-export class SmallWorldSearcher {
+/**
+ * Every target reached by consecutive Small World activations, fewest activations first.
+ */
+export class Chains {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Chains.prototype);
+        obj.__wbg_ptr = ptr;
+        ChainsFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
     __destroy_into_raw() {
         const ptr = this.__wbg_ptr;
         this.__wbg_ptr = 0;
-        SmallWorldSearcherFinalization.unregister(this);
+        ChainsFinalization.unregister(this);
         return ptr;
     }
     free() {
         const ptr = this.__destroy_into_raw();
-        wasm.__wbg_smallworldsearcher_free(ptr, 0);
+        wasm.__wbg_chains_free(ptr, 0);
     }
     /**
-     * Find monsters that connect every source monster to every target monster.
-     * @param {Uint32Array} source
-     * @param {Uint32Array} target
-     * @returns {Monster[] | undefined}
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
      */
-    find_common_bridges(source, target) {
-        const ptr0 = passArray32ToWasm0(source, wasm.__wbindgen_malloc);
-        const len0 = WASM_VECTOR_LEN;
-        const ptr1 = passArray32ToWasm0(target, wasm.__wbindgen_malloc);
-        const len1 = WASM_VECTOR_LEN;
-        const ret = wasm.smallworldsearcher_find_common_bridges(this.__wbg_ptr, ptr0, len0, ptr1, len1);
-        let v3;
-        if (ret[0] !== 0) {
-            v3 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
-            wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
-        }
-        return v3;
+    get diagnostics() {
+        const ret = wasm.chains_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
     }
     /**
-     * For every monster in the pool, find to which other monsters from the pool it can link to, excluding self-links.
+     * @returns {Chain[]}
+     */
+    get chains() {
+        const ret = wasm.chains_chains(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) Chains.prototype[Symbol.dispose] = Chains.prototype.free;
+
+/**
+ * Describes when two monsters are connected.
+ *
+ * Small World itself connects monsters that share exactly one of attribute,
+ * level, type, ATK and DEF, which is the default rule.
+ */
+export class ConnectionRule {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(ConnectionRule.prototype);
+        obj.__wbg_ptr = ptr;
+        ConnectionRuleFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        ConnectionRuleFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_connectionrule_free(ptr, 0);
+    }
+    /**
+     * The Small World rule: exactly one of attribute, level, type, ATK and DEF.
+     * @returns {ConnectionRule}
+     */
+    static small_world() {
+        const ret = wasm.connectionrule_small_world();
+        return ConnectionRule.__wrap(ret);
+    }
+    /**
+     * Monsters may share at most n of the given properties.
+     * @param {any[]} properties
+     * @param {number} n
+     * @returns {ConnectionRule}
+     */
+    static at_most(properties, n) {
+        const ptr0 = passArrayJsValueToWasm0(properties, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.connectionrule_at_most(ptr0, len0, n);
+        return ConnectionRule.__wrap(ret);
+    }
+    /**
+     * Monsters have to share exactly n of the given properties.
+     * @param {any[]} properties
+     * @param {number} n
+     * @returns {ConnectionRule}
+     */
+    static exactly(properties, n) {
+        const ptr0 = passArrayJsValueToWasm0(properties, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.connectionrule_exactly(ptr0, len0, n);
+        return ConnectionRule.__wrap(ret);
+    }
+    /**
+     * Monsters have to share at least n of the given properties.
+     * @param {any[]} properties
+     * @param {number} n
+     * @returns {ConnectionRule}
+     */
+    static at_least(properties, n) {
+        const ptr0 = passArrayJsValueToWasm0(properties, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.connectionrule_at_least(ptr0, len0, n);
+        return ConnectionRule.__wrap(ret);
+    }
+}
+if (Symbol.dispose) ConnectionRule.prototype[Symbol.dispose] = ConnectionRule.prototype.free;
+
+/**
+ * A value of the card database this version does not know, the monster is loaded anyway,
+ * or a row that was skipped when loading leniently.
+ */
+export class CsvWarning {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(CsvWarning.prototype);
+        obj.__wbg_ptr = ptr;
+        CsvWarningFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        CsvWarningFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_csvwarning_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    get row() {
+        const ret = wasm.csvwarning_row(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {string}
+     */
+    get value() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.csvwarning_value(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {string}
+     */
+    get column() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.csvwarning_column(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {string}
+     */
+    get message() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.csvwarning_message(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * whether the monster of the row is missing from the database
+     * @returns {boolean}
+     */
+    get skipped() {
+        const ret = wasm.csvwarning_skipped(this.__wbg_ptr);
+        return ret !== 0;
+    }
+}
+if (Symbol.dispose) CsvWarning.prototype[Symbol.dispose] = CsvWarning.prototype.free;
+
+/**
+ * A decklist storing the number of copies of every passcode per section.
+ * Passcodes keep the order in which they were first added.
+ */
+export class Deck {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Deck.prototype);
+        obj.__wbg_ptr = ptr;
+        DeckFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        DeckFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_deck_free(ptr, 0);
+    }
+    /**
+     * Passcodes of the section without duplicates.
+     * @param {Section} section
+     * @returns {Uint32Array}
+     */
+    unique_ids(section) {
+        const ret = wasm.deck_unique_ids(this.__wbg_ptr, section);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @param {Section} section
+     * @param {number} id
+     * @param {number} copies
+     */
+    add(section, id, copies) {
+        wasm.deck_add(this.__wbg_ptr, section, id, copies);
+    }
+    /**
+     * All passcodes of the section, one entry per copy.
+     * @param {Section} section
+     * @returns {Uint32Array}
+     */
+    ids(section) {
+        const ret = wasm.deck_ids(this.__wbg_ptr, section);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * Number of cards in the section.
+     * @param {Section} section
+     * @returns {number}
+     */
+    len(section) {
+        const ret = wasm.deck_len(this.__wbg_ptr, section);
+        return ret >>> 0;
+    }
+    constructor() {
+        const ret = wasm.deck_new();
+        this.__wbg_ptr = ret >>> 0;
+        DeckFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * Every change needed to turn this deck into `other`.
+     * @param {Deck} other
+     * @returns {DeckChange[]}
+     */
+    diff(other) {
+        _assertClass(other, Deck);
+        const ret = wasm.deck_diff(this.__wbg_ptr, other.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * Copies of the passcode in the section.
+     * @param {Section} section
+     * @param {number} id
+     * @returns {number}
+     */
+    count(section, id) {
+        const ret = wasm.deck_count(this.__wbg_ptr, section, id);
+        return ret >>> 0;
+    }
+    /**
+     * Copies of the passcode over all sections.
+     * @param {number} id
+     * @returns {number}
+     */
+    copies(id) {
+        const ret = wasm.deck_copies(this.__wbg_ptr, id);
+        return ret >>> 0;
+    }
+    /**
+     * Copies of every passcode, in the order of `unique_ids`.
+     * @param {Section} section
+     * @returns {Uint32Array}
+     */
+    counts(section) {
+        const ret = wasm.deck_counts(this.__wbg_ptr, section);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * Removes up to `copies` copies, returns how many were removed.
+     * @param {Section} section
+     * @param {number} id
+     * @param {number} copies
+     * @returns {number}
+     */
+    remove(section, id, copies) {
+        const ret = wasm.deck_remove(this.__wbg_ptr, section, id, copies);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    is_empty() {
+        const ret = wasm.deck_is_empty(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * Checks section sizes and the copy limit, returns every violated rule.
+     * @returns {DeckIssue[]}
+     */
+    validate() {
+        const ret = wasm.deck_validate(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) Deck.prototype[Symbol.dispose] = Deck.prototype.free;
+
+/**
+ * The number of copies of a passcode in a section differs between two decks.
+ */
+export class DeckChange {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(DeckChange.prototype);
+        obj.__wbg_ptr = ptr;
+        DeckChangeFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        DeckChangeFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_deckchange_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    get id() {
+        const ret = wasm.deckchange_id(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * copies in the other deck
+     * @returns {number}
+     */
+    get to() {
+        const ret = wasm.deckchange_to(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * copies in the original deck
+     * @returns {number}
+     */
+    get from() {
+        const ret = wasm.deckchange_from(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {Section}
+     */
+    get section() {
+        const ret = wasm.deckchange_section(this.__wbg_ptr);
+        return ret;
+    }
+}
+if (Symbol.dispose) DeckChange.prototype[Symbol.dispose] = DeckChange.prototype.free;
+
+/**
+ * Metrics of a deck graph, per card lists are aligned with `ids`.
+ */
+export class DeckGraphStats {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(DeckGraphStats.prototype);
+        obj.__wbg_ptr = ptr;
+        DeckGraphStatsFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        DeckGraphStatsFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_deckgraphstats_free(ptr, 0);
+    }
+    /**
+     * connected component of each card, numbered from 0
+     * @returns {Uint32Array}
+     */
+    get components() {
+        const ret = wasm.deckgraphstats_components(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
+     */
+    get diagnostics() {
+        const ret = wasm.deckgraphstats_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
+    }
+    /**
+     * cards no other card can reach
+     * @returns {Uint32Array}
+     */
+    get unreachable() {
+        const ret = wasm.deckgraphstats_unreachable(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * share of each bridge in connecting the pairs of the deck: every unordered pair that can
+     * be linked counts 1, split evenly over all of its bridges. Unlike graph betweenness,
+     * only the direct reveal -> bridge -> target paths are counted, not shortest paths.
+     * @returns {Float64Array}
+     */
+    get bridge_share() {
+        const ret = wasm.deckgraphstats_bridge_share(this.__wbg_ptr);
+        var v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
+        return v1;
+    }
+    /**
+     * number of pairs each critical bridge is the only route for
+     * @returns {Uint32Array}
+     */
+    get critical_pairs() {
+        const ret = wasm.deckgraphstats_critical_pairs(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @returns {number}
+     */
+    get component_count() {
+        const ret = wasm.deckgraphstats_component_count(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * bridges that are the only route for at least one pair
+     * @returns {Uint32Array}
+     */
+    get critical_bridges() {
+        const ret = wasm.deckgraphstats_critical_bridges(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * every card of the deck known to the searcher
+     * @returns {Uint32Array}
+     */
+    get ids() {
+        const ret = wasm.deckgraphstats_ids(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * every card that acts as bridge for at least one pair
+     * @returns {Uint32Array}
+     */
+    get bridges() {
+        const ret = wasm.deckgraphstats_bridges(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * number of cards each card can reach
+     * @returns {Uint32Array}
+     */
+    get degrees() {
+        const ret = wasm.deckgraphstats_degrees(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * cards that can not reach any other card
+     * @returns {Uint32Array}
+     */
+    get dead_ends() {
+        const ret = wasm.deckgraphstats_dead_ends(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) DeckGraphStats.prototype[Symbol.dispose] = DeckGraphStats.prototype.free;
+
+/**
+ * A violated deck building rule.
+ */
+export class DeckIssue {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(DeckIssue.prototype);
+        obj.__wbg_ptr = ptr;
+        DeckIssueFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        DeckIssueFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_deckissue_free(ptr, 0);
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get id() {
+        const ret = wasm.deckissue_id(this.__wbg_ptr);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+    /**
+     * @returns {DeckIssueKind}
+     */
+    get kind() {
+        const ret = wasm.deckissue_kind(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get count() {
+        const ret = wasm.deckchange_to(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {string}
+     */
+    get message() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.deckissue_message(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+}
+if (Symbol.dispose) DeckIssue.prototype[Symbol.dispose] = DeckIssue.prototype.free;
+
+/**
+ * @enum {0 | 1 | 2 | 3 | 4}
+ */
+export const DeckIssueKind = Object.freeze({
+    MainDeckTooSmall: 0, "0": "MainDeckTooSmall",
+    MainDeckTooLarge: 1, "1": "MainDeckTooLarge",
+    ExtraDeckTooLarge: 2, "2": "ExtraDeckTooLarge",
+    SideDeckTooLarge: 3, "3": "SideDeckTooLarge",
+    TooManyCopies: 4, "4": "TooManyCopies",
+});
+
+/**
+ * Every passcode of a query that did not map directly to a monster of the database.
+ */
+export class Diagnostics {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Diagnostics.prototype);
+        obj.__wbg_ptr = ptr;
+        DiagnosticsFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        DiagnosticsFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_diagnostics_free(ptr, 0);
+    }
+    /**
+     * passcodes that were left out of the query, aliases are not counted
+     * @returns {Uint32Array}
+     */
+    dropped_ids() {
+        const ret = wasm.diagnostics_dropped_ids(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * ignored and replaced passcodes, in the order they appeared in the query
+     * @returns {IgnoredId[]}
+     */
+    get ignored() {
+        const ret = wasm.diagnostics_ignored(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * whether every passcode was used as given
+     * @returns {boolean}
+     */
+    is_empty() {
+        const ret = wasm.diagnostics_is_empty(this.__wbg_ptr);
+        return ret !== 0;
+    }
+}
+if (Symbol.dispose) Diagnostics.prototype[Symbol.dispose] = Diagnostics.prototype.free;
+
+/**
+ * A filter expression that could not be parsed.
+ */
+export class FilterError {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(FilterError.prototype);
+        obj.__wbg_ptr = ptr;
+        FilterErrorFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        FilterErrorFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_filtererror_free(ptr, 0);
+    }
+    /**
+     * @returns {string}
+     */
+    get message() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.filtererror_message(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * character offset in the query where the error was found
+     * @returns {number}
+     */
+    get position() {
+        const ret = wasm.filtererror_position(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+}
+if (Symbol.dispose) FilterError.prototype[Symbol.dispose] = FilterError.prototype.free;
+
+/**
+ * The passcodes that match a filter, in the order of the query.
+ */
+export class FilteredIds {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(FilteredIds.prototype);
+        obj.__wbg_ptr = ptr;
+        FilteredIdsFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        FilteredIdsFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_filteredids_free(ptr, 0);
+    }
+    /**
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
+     */
+    get diagnostics() {
+        const ret = wasm.filteredids_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
+    }
+    /**
+     * @returns {Uint32Array}
+     */
+    get ids() {
+        const ret = wasm.filteredids_ids(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) FilteredIds.prototype[Symbol.dispose] = FilteredIds.prototype.free;
+
+/**
+ * The card frame of a Main Deck monster.
+ * @enum {0 | 1 | 2 | 3 | 4 | 5}
+ */
+export const Frame = Object.freeze({
+    Normal: 0, "0": "Normal",
+    Effect: 1, "1": "Effect",
+    Ritual: 2, "2": "Ritual",
+    NormalPendulum: 3, "3": "NormalPendulum",
+    EffectPendulum: 4, "4": "EffectPendulum",
+    RitualPendulum: 5, "5": "RitualPendulum",
+});
+
+/**
+ * The zones touched by Small World, each card is one entry per copy.
+ * Alternate artworks are stored with the passcode of the card database.
+ */
+export class GameState {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        GameStateFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_gamestate_free(ptr, 0);
+    }
+    /**
+     * Resolves Small World: the revealed monster is banished face-down from the hand,
+     * the target is added from the deck to the hand, then the bridge is banished face-down
+     * from the deck. Small World itself is sent to the graveyard.
+     * Nothing changes if the activation is not legal.
+     * @param {SmallWorldSearcher} searcher
+     * @param {number} reveal
+     * @param {number} bridge
+     * @param {number} target
+     */
+    apply_small_world(searcher, reveal, bridge, target) {
+        _assertClass(searcher, SmallWorldSearcher);
+        const ret = wasm.gamestate_apply_small_world(this.__wbg_ptr, searcher.__wbg_ptr, reveal, bridge, target);
+        if (ret[1]) {
+            throw takeFromExternrefTable0(ret[0]);
+        }
+    }
+    /**
+     * Checks whether Small World can be resolved with the given cards, without changing the zones.
+     * @param {SmallWorldSearcher} searcher
+     * @param {number} reveal
+     * @param {number} bridge
+     * @param {number} target
+     * @returns {ActivationError | undefined}
+     */
+    check_small_world(searcher, reveal, bridge, target) {
+        _assertClass(searcher, SmallWorldSearcher);
+        const ret = wasm.gamestate_check_small_world(this.__wbg_ptr, searcher.__wbg_ptr, reveal, bridge, target);
+        return ret === 7 ? undefined : ret;
+    }
+    /**
+     * `deck` holds the cards left in the main deck, without the hand.
+     * Passcodes are resolved with the alias table of the searcher.
+     * @param {Uint32Array} hand_ids
+     * @param {Deck} deck
+     * @param {SmallWorldSearcher} searcher
+     */
+    constructor(hand_ids, deck, searcher) {
+        const ptr0 = passArray32ToWasm0(hand_ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(deck, Deck);
+        _assertClass(searcher, SmallWorldSearcher);
+        const ret = wasm.gamestate_new(ptr0, len0, deck.__wbg_ptr, searcher.__wbg_ptr);
+        this.__wbg_ptr = ret >>> 0;
+        GameStateFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * @returns {Deck}
+     */
+    get deck() {
+        const ret = wasm.gamestate_deck(this.__wbg_ptr);
+        return Deck.__wrap(ret);
+    }
+    /**
+     * Moves a copy of the card from the deck to the hand, returns false if none is left.
+     * @param {number} id
+     * @returns {boolean}
+     */
+    draw(id) {
+        const ret = wasm.gamestate_draw(this.__wbg_ptr, id);
+        return ret !== 0;
+    }
+    /**
+     * @returns {Uint32Array}
+     */
+    get hand() {
+        const ret = wasm.gamestate_hand(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * Every line that can be played from the current hand into the current deck.
+     * @param {SmallWorldSearcher} searcher
+     * @returns {HandLines}
+     */
+    lines(searcher) {
+        _assertClass(searcher, SmallWorldSearcher);
+        const ret = wasm.gamestate_lines(this.__wbg_ptr, searcher.__wbg_ptr);
+        return HandLines.__wrap(ret);
+    }
+    /**
+     * cards banished face-down, in the order they were banished
+     * @returns {Uint32Array}
+     */
+    get banished() {
+        const ret = wasm.gamestate_banished(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * resolved copies of Small World
+     * @returns {Uint32Array}
+     */
+    get graveyard() {
+        const ret = wasm.gamestate_graveyard(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) GameState.prototype[Symbol.dispose] = GameState.prototype.free;
+
+/**
+ * Every Small World line available from a concrete hand.
+ */
+export class HandLines {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(HandLines.prototype);
+        obj.__wbg_ptr = ptr;
+        HandLinesFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        HandLinesFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_handlines_free(ptr, 0);
+    }
+    /**
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
+     */
+    get diagnostics() {
+        const ret = wasm.handlines_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
+    }
+    /**
+     * whether Small World is in the hand, without it none of the lines can be played
+     * @returns {boolean}
+     */
+    get has_small_world() {
+        const ret = wasm.handlines_has_small_world(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {PathExplanation[]}
+     */
+    get lines() {
+        const ret = wasm.handlines_lines(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) HandLines.prototype[Symbol.dispose] = HandLines.prototype.free;
+
+/**
+ * One step of a Small World path, described by the properties the two monsters share.
+ * Under the default rule this is exactly one property.
+ */
+export class Hop {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Hop.prototype);
+        obj.__wbg_ptr = ptr;
+        HopFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        HopFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_hop_free(ptr, 0);
+    }
+    /**
+     * @returns {any[]}
+     */
+    get properties() {
+        const ret = wasm.hop_properties(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @returns {string[]}
+     */
+    get values() {
+        const ret = wasm.hop_values(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) Hop.prototype[Symbol.dispose] = Hop.prototype.free;
+
+/**
+ * @enum {0 | 1 | 2 | 3}
+ */
+export const IgnoreReason = Object.freeze({
+    /**
+     * the passcode is unknown
+     */
+    NotInDatabase: 0, "0": "NotInDatabase",
+    /**
+     * a Spell, Trap or Extra Deck monster, Small World can not search it
+     */
+    NotMainDeckMonster: 1, "1": "NotMainDeckMonster",
+    /**
+     * an alternate artwork, the query used the canonical passcode instead
+     */
+    AliasResolved: 2, "2": "AliasResolved",
+    /**
+     * a monster past the 128 distinct monsters the odds of a deck are computed for
+     */
+    OverMonsterLimit: 3, "3": "OverMonsterLimit",
+});
+
+/**
+ * A passcode of a query that was ignored or replaced.
+ */
+export class IgnoredId {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(IgnoredId.prototype);
+        obj.__wbg_ptr = ptr;
+        IgnoredIdFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        IgnoredIdFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_ignoredid_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    get id() {
+        const ret = wasm.ignoredid_id(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {IgnoreReason}
+     */
+    get reason() {
+        const ret = wasm.ignoredid_reason(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {string}
+     */
+    get message() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.ignoredid_message(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get canonical() {
+        const ret = wasm.ignoredid_canonical(this.__wbg_ptr);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+}
+if (Symbol.dispose) IgnoredId.prototype[Symbol.dispose] = IgnoredId.prototype.free;
+
+export class Link {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Link.prototype);
+        obj.__wbg_ptr = ptr;
+        LinkFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        LinkFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_link_free(ptr, 0);
+    }
+    /**
+     * @returns {Monster}
+     */
+    get start() {
+        const ret = wasm.link_start(this.__wbg_ptr);
+        return Monster.__wrap(ret);
+    }
+    /**
+     * @returns {Monster}
+     */
+    get bridge() {
+        const ret = wasm.link_bridge(this.__wbg_ptr);
+        return Monster.__wrap(ret);
+    }
+    /**
+     * @returns {Monster}
+     */
+    get target() {
+        const ret = wasm.link_target(this.__wbg_ptr);
+        return Monster.__wrap(ret);
+    }
+}
+if (Symbol.dispose) Link.prototype[Symbol.dispose] = Link.prototype.free;
+
+/**
+ * Links within a pool grouped by source/target pair, stored as flat arrays.
+ *
+ * Group i connects `sources[i]` and `targets[i]` through the bridges
+ * `bridges[bridge_offsets[i]..bridge_offsets[i + 1]]`.
+ */
+export class LinkGroups {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(LinkGroups.prototype);
+        obj.__wbg_ptr = ptr;
+        LinkGroupsFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        LinkGroupsFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_linkgroups_free(ptr, 0);
+    }
+    /**
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
+     */
+    get diagnostics() {
+        const ret = wasm.linkgroups_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
+    }
+    /**
+     * start of every group in `bridges`, followed by the total number of bridges
+     * @returns {Uint32Array}
+     */
+    get bridge_offsets() {
+        const ret = wasm.linkgroups_bridge_offsets(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * number of groups
+     * @returns {number}
+     */
+    get len() {
+        const ret = wasm.linkgroups_len(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {Uint32Array}
+     */
+    get bridges() {
+        const ret = wasm.linkgroups_bridges(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @returns {Uint32Array}
+     */
+    get sources() {
+        const ret = wasm.linkgroups_sources(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @returns {Uint32Array}
+     */
+    get targets() {
+        const ret = wasm.linkgroups_targets(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @returns {boolean}
+     */
+    is_empty() {
+        const ret = wasm.linkgroups_is_empty(this.__wbg_ptr);
+        return ret !== 0;
+    }
+}
+if (Symbol.dispose) LinkGroups.prototype[Symbol.dispose] = LinkGroups.prototype.free;
+
+/**
+ * The links within a pool.
+ */
+export class Links {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Links.prototype);
+        obj.__wbg_ptr = ptr;
+        LinksFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        LinksFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_links_free(ptr, 0);
+    }
+    /**
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
+     */
+    get diagnostics() {
+        const ret = wasm.links_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
+    }
+    /**
+     * @returns {Link[]}
+     */
+    get links() {
+        const ret = wasm.links_links(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) Links.prototype[Symbol.dispose] = Links.prototype.free;
+
+export class Monster {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Monster.prototype);
+        obj.__wbg_ptr = ptr;
+        MonsterFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        MonsterFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_monster_free(ptr, 0);
+    }
+    /**
+     * @returns {BanStatus}
+     */
+    get ban_status() {
+        const ret = wasm.monster_ban_status(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {any[]}
+     */
+    get abilities_js() {
+        const ret = wasm.monster_abilities_js(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @returns {string}
+     */
+    get attribute_js() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.monster_attribute_js(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {string | undefined}
+     */
+    get release_date() {
+        const ret = wasm.monster_release_date(this.__wbg_ptr);
+        let v1;
+        if (ret[0] !== 0) {
+            v1 = getStringFromWasm0(ret[0], ret[1]).slice();
+            wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+        }
+        return v1;
+    }
+    /**
+     * @returns {number}
+     */
+    get id() {
+        const ret = wasm.monster_id(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get atk() {
+        const ret = wasm.ignoredid_canonical(this.__wbg_ptr);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get def() {
+        const ret = wasm.monster_def(this.__wbg_ptr);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+    /**
+     * `None` if the card database has no frames
+     * @returns {Frame | undefined}
+     */
+    get frame() {
+        const ret = wasm.monster_frame(this.__wbg_ptr);
+        return ret === 6 ? undefined : ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get level() {
+        const ret = wasm.monster_level(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number | undefined}
+     */
+    get scale() {
+        const ret = wasm.monster_scale(this.__wbg_ptr);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+    /**
+     * @returns {string}
+     */
+    get name_js() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.monster_name_js(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {string}
+     */
+    get type_js() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const ret = wasm.monster_type_js(this.__wbg_ptr);
+            deferred1_0 = ret[0];
+            deferred1_1 = ret[1];
+            return getStringFromWasm0(ret[0], ret[1]);
+        } finally {
+            wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {string | undefined}
+     */
+    get archetype() {
+        const ret = wasm.monster_archetype(this.__wbg_ptr);
+        let v1;
+        if (ret[0] !== 0) {
+            v1 = getStringFromWasm0(ret[0], ret[1]).slice();
+            wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+        }
+        return v1;
+    }
+}
+if (Symbol.dispose) Monster.prototype[Symbol.dispose] = Monster.prototype.free;
+
+/**
+ * Chance to open Small World together with a way to reach the targets.
+ */
+export class OpeningOdds {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(OpeningOdds.prototype);
+        obj.__wbg_ptr = ptr;
+        OpeningOddsFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        OpeningOddsFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_openingodds_free(ptr, 0);
+    }
+    /**
+     * probability to reach at least one of the targets
+     * @returns {number}
+     */
+    get any_target() {
+        const ret = wasm.openingodds_any_target(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * probability to reach each target, in the order of `targets`
+     * @returns {Float64Array}
+     */
+    get per_target() {
+        const ret = wasm.openingodds_per_target(this.__wbg_ptr);
+        var v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
+        return v1;
+    }
+    /**
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
+     */
+    get diagnostics() {
+        const ret = wasm.openingodds_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
+    }
+    /**
+     * probability to open at least one Small World
+     * @returns {number}
+     */
+    get small_world() {
+        const ret = wasm.openingodds_small_world(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {Uint32Array}
+     */
+    get targets() {
+        const ret = wasm.openingodds_targets(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) OpeningOdds.prototype[Symbol.dispose] = OpeningOdds.prototype.free;
+
+/**
+ * The best deck found by the optimizer.
+ */
+export class OptimizedDeck {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(OptimizedDeck.prototype);
+        obj.__wbg_ptr = ptr;
+        OptimizedDeckFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        OptimizedDeckFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_optimizeddeck_free(ptr, 0);
+    }
+    /**
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
+     */
+    get diagnostics() {
+        const ret = wasm.optimizeddeck_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
+    }
+    /**
+     * number of decks whose odds were computed
+     * @returns {number}
+     */
+    get evaluations() {
+        const ret = wasm.optimizeddeck_evaluations(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * the core with the selected candidates added to the main deck
+     * @returns {Deck}
+     */
+    get deck() {
+        const ret = wasm.optimizeddeck_deck(this.__wbg_ptr);
+        return Deck.__wrap(ret);
+    }
+    /**
+     * mean probability to reach each target
+     * @returns {number}
+     */
+    get coverage() {
+        const ret = wasm.optimizeddeck_coverage(this.__wbg_ptr);
+        return ret;
+    }
+}
+if (Symbol.dispose) OptimizedDeck.prototype[Symbol.dispose] = OptimizedDeck.prototype.free;
+
+/**
+ * Settings of the deck optimizer.
+ */
+export class OptimizerConfig {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        OptimizerConfigFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_optimizerconfig_free(ptr, 0);
+    }
+    /**
+     * @param {number} budget
+     * @param {number} hand_size
+     */
+    constructor(budget, hand_size) {
+        const ret = wasm.optimizerconfig_new(budget, hand_size);
+        this.__wbg_ptr = ret >>> 0;
+        OptimizerConfigFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * maximum number of cards added to the core
+     * @returns {number}
+     */
+    get budget() {
+        const ret = wasm.__wbg_get_optimizerconfig_budget(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * maximum number of cards added to the core
+     * @param {number} arg0
+     */
+    set budget(arg0) {
+        wasm.__wbg_set_optimizerconfig_budget(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @returns {number}
+     */
+    get hand_size() {
+        const ret = wasm.__wbg_get_optimizerconfig_hand_size(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @param {number} arg0
+     */
+    set hand_size(arg0) {
+        wasm.__wbg_set_optimizerconfig_hand_size(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @returns {OptimizerStrategy}
+     */
+    get strategy() {
+        const ret = wasm.__wbg_get_optimizerconfig_strategy(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @param {OptimizerStrategy} arg0
+     */
+    set strategy(arg0) {
+        wasm.__wbg_set_optimizerconfig_strategy(this.__wbg_ptr, arg0);
+    }
+    /**
+     * number of swaps tried by the local search
+     * @returns {number}
+     */
+    get iterations() {
+        const ret = wasm.__wbg_get_optimizerconfig_iterations(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * number of swaps tried by the local search
+     * @param {number} arg0
+     */
+    set iterations(arg0) {
+        wasm.__wbg_set_optimizerconfig_iterations(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @returns {bigint}
+     */
+    get seed() {
+        const ret = wasm.__wbg_get_optimizerconfig_seed(this.__wbg_ptr);
+        return BigInt.asUintN(64, ret);
+    }
+    /**
+     * @param {bigint} arg0
+     */
+    set seed(arg0) {
+        wasm.__wbg_set_optimizerconfig_seed(this.__wbg_ptr, arg0);
+    }
+}
+if (Symbol.dispose) OptimizerConfig.prototype[Symbol.dispose] = OptimizerConfig.prototype.free;
+
+/**
+ * @enum {0 | 1}
+ */
+export const OptimizerStrategy = Object.freeze({
+    /**
+     * add the best single copy until the budget is used or nothing improves
+     */
+    Greedy: 0, "0": "Greedy",
+    /**
+     * greedy, followed by random swaps of added copies that are kept if they improve
+     */
+    LocalSearch: 1, "1": "LocalSearch",
+});
+
+export class PathExplanation {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(PathExplanation.prototype);
+        obj.__wbg_ptr = ptr;
+        PathExplanationFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        PathExplanationFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_pathexplanation_free(ptr, 0);
+    }
+    /**
+     * @returns {Hop}
+     */
+    get hand_to_bridge() {
+        const ret = wasm.pathexplanation_hand_to_bridge(this.__wbg_ptr);
+        return Hop.__wrap(ret);
+    }
+    /**
+     * @returns {Hop}
+     */
+    get bridge_to_target() {
+        const ret = wasm.pathexplanation_bridge_to_target(this.__wbg_ptr);
+        return Hop.__wrap(ret);
+    }
+    /**
+     * @returns {number}
+     */
+    get hand() {
+        const ret = wasm.pathexplanation_hand(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get bridge() {
+        const ret = wasm.pathexplanation_bridge(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get target() {
+        const ret = wasm.pathexplanation_target(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+}
+if (Symbol.dispose) PathExplanation.prototype[Symbol.dispose] = PathExplanation.prototype.free;
+
+/**
+ * All paths between one hand monster and one target monster.
+ */
+export class PathGroup {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(PathGroup.prototype);
+        obj.__wbg_ptr = ptr;
+        PathGroupFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        PathGroupFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_pathgroup_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    get hand() {
+        const ret = wasm.chain_target(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {PathExplanation[]}
+     */
+    get paths() {
+        const ret = wasm.pathgroup_paths(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @returns {number}
+     */
+    get target() {
+        const ret = wasm.chain_activations(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+}
+if (Symbol.dispose) PathGroup.prototype[Symbol.dispose] = PathGroup.prototype.free;
+
+/**
+ * The paths of every hand/target pair that has at least one bridge.
+ */
+export class PathGroups {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(PathGroups.prototype);
+        obj.__wbg_ptr = ptr;
+        PathGroupsFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        PathGroupsFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_pathgroups_free(ptr, 0);
+    }
+    /**
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
+     */
+    get diagnostics() {
+        const ret = wasm.pathgroups_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
+    }
+    /**
+     * @returns {PathGroup[]}
+     */
+    get groups() {
+        const ret = wasm.pathgroups_groups(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) PathGroups.prototype[Symbol.dispose] = PathGroups.prototype.free;
+
+/**
+ * The properties compared by Small World.
+ * @enum {0 | 1 | 2 | 3 | 4}
+ */
+export const Property = Object.freeze({
+    Attribute: 0, "0": "Attribute",
+    Level: 1, "1": "Level",
+    Type: 2, "2": "Type",
+    Atk: 3, "3": "Atk",
+    Def: 4, "4": "Def",
+});
+
+/**
+ * A monster suggested as an additional bridge for a deck.
+ */
+export class Recommendation {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Recommendation.prototype);
+        obj.__wbg_ptr = ptr;
+        RecommendationFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        RecommendationFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_recommendation_free(ptr, 0);
+    }
+    /**
+     * number of unordered hand/target pairs the bridge newly connects
+     * @returns {number}
+     */
+    get new_connections() {
+        const ret = wasm.chain_activations(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get bridge() {
+        const ret = wasm.chain_target(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * deck monsters the bridge connects to
+     * @returns {Uint32Array}
+     */
+    get connects() {
+        const ret = wasm.recommendation_connects(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) Recommendation.prototype[Symbol.dispose] = Recommendation.prototype.free;
+
+/**
+ * The bridges suggested for a deck, best first.
+ */
+export class Recommendations {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Recommendations.prototype);
+        obj.__wbg_ptr = ptr;
+        RecommendationsFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        RecommendationsFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_recommendations_free(ptr, 0);
+    }
+    /**
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
+     */
+    get diagnostics() {
+        const ret = wasm.recommendations_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
+    }
+    /**
+     * @returns {Recommendation[]}
+     */
+    get recommendations() {
+        const ret = wasm.recommendations_recommendations(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) Recommendations.prototype[Symbol.dispose] = Recommendations.prototype.free;
+
+/**
+ * @enum {0 | 1 | 2}
+ */
+export const Section = Object.freeze({
+    Main: 0, "0": "Main",
+    Extra: 1, "1": "Extra",
+    Side: 2, "2": "Side",
+});
+
+/**
+ * Success rates of simulated opening hands, with 95% confidence intervals.
+ */
+export class SimulationResult {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(SimulationResult.prototype);
+        obj.__wbg_ptr = ptr;
+        SimulationResultFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SimulationResultFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_simulationresult_free(ptr, 0);
+    }
+    /**
+     * number of hands that reached at least one target
+     * @returns {number}
+     */
+    get any_target() {
+        const ret = wasm.simulationresult_any_target(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
+     */
+    get diagnostics() {
+        const ret = wasm.simulationresult_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
+    }
+    /**
+     * number of hands that opened Small World
+     * @returns {number}
+     */
+    get small_world() {
+        const ret = wasm.simulationresult_small_world(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {Float64Array}
+     */
+    get lower_bounds() {
+        const ret = wasm.simulationresult_lower_bounds(this.__wbg_ptr);
+        var v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
+        return v1;
+    }
+    /**
+     * @returns {Float64Array}
+     */
+    get upper_bounds() {
+        const ret = wasm.simulationresult_upper_bounds(this.__wbg_ptr);
+        var v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
+        return v1;
+    }
+    /**
+     * success rate of each target
+     * @returns {Float64Array}
+     */
+    get rates() {
+        const ret = wasm.simulationresult_rates(this.__wbg_ptr);
+        var v1 = getArrayF64FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 8, 8);
+        return v1;
+    }
+    /**
+     * @returns {number}
+     */
+    get trials() {
+        const ret = wasm.simulationresult_trials(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {Uint32Array}
+     */
+    get targets() {
+        const ret = wasm.simulationresult_targets(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * number of hands that reached each target, in the order of `targets`
+     * @returns {Uint32Array}
+     */
+    get successes() {
+        const ret = wasm.simulationresult_successes(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) SimulationResult.prototype[Symbol.dispose] = SimulationResult.prototype.free;
+
+export class SmallWorldSearcher {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(SmallWorldSearcher.prototype);
+        obj.__wbg_ptr = ptr;
+        SmallWorldSearcherFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SmallWorldSearcherFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_smallworldsearcher_free(ptr, 0);
+    }
+    /**
+     * The passcodes of monsters that match the filter, in the given order,
+     * e.g. to narrow down bridge results. Passcodes not in the database are dropped.
+     * @param {CardFilter} filter
+     * @param {Uint32Array} ids
+     * @returns {FilteredIds}
+     */
+    filter_ids(filter, ids) {
+        _assertClass(filter, CardFilter);
+        const ptr0 = passArray32ToWasm0(ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_filter_ids(this.__wbg_ptr, filter.__wbg_ptr, ptr0, len0);
+        return FilteredIds.__wrap(ret);
+    }
+    /**
+     * Find every path hand -> bridge -> target for all given hand and target monsters.
+     * Paths are grouped per hand/target pair, pairs without any bridge are left out.
+     * @param {Uint32Array} hand_ids
+     * @param {Uint32Array} target_ids
+     * @returns {PathGroups}
+     */
+    find_paths(hand_ids, target_ids) {
+        const ptr0 = passArray32ToWasm0(hand_ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArray32ToWasm0(target_ids, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_find_paths(this.__wbg_ptr, ptr0, len0, ptr1, len1);
+        return PathGroups.__wrap(ret);
+    }
+    /**
+     * Find every pool monster reachable from the hand monster with at most `max_activations`
+     * consecutive Small World resolutions, where each target is revealed for the next activation.
+     * Every target is reported with the minimum number of activations and one shortest path.
+     * @param {number} hand_id
+     * @param {Uint32Array} pool_ids
+     * @param {number} max_activations
+     * @returns {Chains}
+     */
+    find_chains(hand_id, pool_ids, max_activations) {
+        const ptr0 = passArray32ToWasm0(pool_ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_find_chains(this.__wbg_ptr, hand_id, ptr0, len0, max_activations);
+        return Chains.__wrap(ret);
+    }
+    /**
+     * Replace the alternate artwork passcodes loaded with the card database, all following
+     * queries accept them in place of the canonical passcodes.
+     * @param {AliasTable} aliases
+     */
+    set_aliases(aliases) {
+        _assertClass(aliases, AliasTable);
+        wasm.smallworldsearcher_set_aliases(this.__wbg_ptr, aliases.__wbg_ptr);
+    }
+    /**
+     * The passcode the database uses for the monster, resolving alternate artworks.
+     * `None` if the monster is not in the database.
+     * @param {number} id
+     * @returns {number | undefined}
+     */
+    canonical_id(id) {
+        const ret = wasm.smallworldsearcher_canonical_id(this.__wbg_ptr, id);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+    /**
+     * Explain the path hand -> bridge -> target by the properties shared on each hop.
+     * Returns None if one of the monsters is unknown or a hop is not a valid connection.
+     * @param {number} hand
+     * @param {number} bridge
+     * @param {number} target
+     * @returns {PathExplanation | undefined}
+     */
+    explain_path(hand, bridge, target) {
+        const ret = wasm.smallworldsearcher_explain_path(this.__wbg_ptr, hand, bridge, target);
+        return ret === 0 ? undefined : PathExplanation.__wrap(ret);
+    }
+    /**
+     * Find every monster that can reach the target through at least one bridge.
+     * If a pool is given, both the source and the bridge have to be part of it,
+     * otherwise the whole card pool is searched. The target itself is never a source.
+     * @param {number} target_id
+     * @param {Uint32Array | null} [pool_ids]
+     * @returns {Sources}
+     */
+    find_sources(target_id, pool_ids) {
+        var ptr0 = isLikeNone(pool_ids) ? 0 : passArray32ToWasm0(pool_ids, wasm.__wbindgen_malloc);
+        var len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_find_sources(this.__wbg_ptr, target_id, ptr0, len0);
+        return Sources.__wrap(ret);
+    }
+    /**
+     * Exact probability to open Small World together with a monster that reaches the targets,
+     * for a hand of the given size drawn from the main deck. See `probability::opening_odds`.
+     * @param {Deck} deck
+     * @param {number} hand_size
+     * @param {Uint32Array} target_ids
+     * @returns {OpeningOdds}
+     */
+    opening_odds(deck, hand_size, target_ids) {
+        _assertClass(deck, Deck);
+        const ptr0 = passArray32ToWasm0(target_ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_opening_odds(this.__wbg_ptr, deck.__wbg_ptr, hand_size, ptr0, len0);
+        return OpeningOdds.__wrap(ret);
+    }
+    /**
+     * Parse a filter expression like `CardFilter.parse`, attributes and types this version
+     * does not know are accepted too if the card database has them.
+     * @param {string} query
+     * @returns {CardFilter}
+     */
+    parse_filter(query) {
+        const ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_parse_filter(this.__wbg_ptr, ptr0, len0);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return CardFilter.__wrap(ret[0]);
+    }
+    /**
+     * Passcode of the monster with the given name, allowing for small typos,
+     * e.g. to import a plain-text decklist. `None` if no name is close enough.
+     * @param {string} name
+     * @returns {number | undefined}
+     */
+    resolve_name(name) {
+        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_resolve_name(this.__wbg_ptr, ptr0, len0);
+        return ret === 0x100000001 ? undefined : ret;
+    }
+    /**
+     * Passcodes of the monsters whose names match the query best, best first.
+     * Tolerates typos and ignores case, punctuation and hyphens.
+     * @param {string} query
+     * @param {number} limit
+     * @returns {Uint32Array}
+     */
+    search_names(query, limit) {
+        const ptr0 = passStringToWasm0(query, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_search_names(this.__wbg_ptr, ptr0, len0, limit);
+        var v2 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v2;
+    }
+    /**
+     * Attributes and types of the card database this version does not know, and skipped rows.
+     * Monsters with unknown values are loaded anyway and only match monsters with the same value.
+     * @returns {CsvWarning[]}
+     */
+    load_warnings() {
+        const ret = wasm.smallworldsearcher_load_warnings(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * Search the copies of the candidate monsters to add to the main deck of the core,
+     * at most `config.budget` cards, that maximize the mean chance to reach each target.
+     * Candidates that are not in the database are ignored.
+     * @param {Deck} core
+     * @param {Uint32Array} candidate_ids
+     * @param {Uint32Array} target_ids
+     * @param {OptimizerConfig} config
+     * @returns {OptimizedDeck}
+     */
+    optimize_deck(core, candidate_ids, target_ids, config) {
+        _assertClass(core, Deck);
+        const ptr0 = passArray32ToWasm0(candidate_ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArray32ToWasm0(target_ids, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(config, OptimizerConfig);
+        const ret = wasm.smallworldsearcher_optimize_deck(this.__wbg_ptr, core.__wbg_ptr, ptr0, len0, ptr1, len1, config.__wbg_ptr);
+        return OptimizedDeck.__wrap(ret);
+    }
+    /**
+     * @returns {UnknownStats}
+     */
+    unknown_stats() {
+        const ret = wasm.smallworldsearcher_unknown_stats(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * The schema version of the loaded card database, older than `csv_schema_version()`
+     * if it was cached before the schema changed.
+     * @returns {number}
+     */
+    get schema_version() {
+        const ret = wasm.smallworldsearcher_schema_version(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * Greedily select up to k bridges to add to the deck. Each step picks the monster
+     * connecting the most pairs not covered by the deck or a previously selected bridge,
+     * so `new_connections` is the marginal gain of every pick.
+     * @param {Uint32Array} deck_ids
+     * @param {number} k
+     * @returns {Recommendations}
+     */
+    select_bridges(deck_ids, k) {
+        const ptr0 = passArray32ToWasm0(deck_ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_select_bridges(this.__wbg_ptr, ptr0, len0, k);
+        return Recommendations.__wrap(ret);
+    }
+    /**
+     * @returns {ConnectionRule}
+     */
+    connection_rule() {
+        const ret = wasm.smallworldsearcher_connection_rule(this.__wbg_ptr);
+        return ConnectionRule.__wrap(ret);
+    }
+    /**
+     * List every reveal -> bridge -> target line of a concrete hand. Bridge and target have to be
+     * cards left in the main deck of `deck`, so the same passcode can only be both with two copies.
+     * @param {Uint32Array} hand_ids
+     * @param {Deck} deck
+     * @returns {HandLines}
+     */
+    find_hand_lines(hand_ids, deck) {
+        const ptr0 = passArray32ToWasm0(hand_ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(deck, Deck);
+        const ret = wasm.smallworldsearcher_find_hand_lines(this.__wbg_ptr, ptr0, len0, deck.__wbg_ptr);
+        return HandLines.__wrap(ret);
+    }
+    /**
+     * Replace the passcodes of Spells, Traps and Extra Deck monsters loaded with the card database,
+     * so diagnostics can tell them apart from passcodes that are not in the database at all.
+     * @param {Uint32Array} ids
+     */
+    set_other_cards(ids) {
+        const ptr0 = passArray32ToWasm0(ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.smallworldsearcher_set_other_cards(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * Like the constructor, but rows that can not be parsed are skipped and
+     * reported in `load_warnings` instead of failing.
+     * @param {string} data
+     * @returns {SmallWorldSearcher}
+     */
+    static from_csv_lenient(data) {
+        const ptr0 = passStringToWasm0(data, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_from_csv_lenient(ptr0, len0);
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        return SmallWorldSearcher.__wrap(ret[0]);
+    }
+    /**
+     * Rank monsters outside the deck by how many new hand/target pairs of the deck
+     * they would connect if added as a bridge. Pairs are unordered and only pairs
+     * that can not already be connected through a bridge in the deck are counted.
+     * @param {Uint32Array} deck_ids
+     * @param {number} limit
+     * @returns {Recommendations}
+     */
+    recommend_bridges(deck_ids, limit) {
+        const ptr0 = passArray32ToWasm0(deck_ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_recommend_bridges(this.__wbg_ptr, ptr0, len0, limit);
+        return Recommendations.__wrap(ret);
+    }
+    /**
+     * Replace how unknown ("?") ATK and DEF are compared, all following queries use it.
+     * @param {UnknownStats} unknown
+     */
+    set_unknown_stats(unknown) {
+        wasm.smallworldsearcher_set_unknown_stats(this.__wbg_ptr, unknown);
+    }
+    /**
+     * Estimate the opening odds by shuffling the main deck `trials` times, taking into account
+     * that bridges and targets drawn into the hand are no longer in the deck.
+     * The same seed always gives the same result.
+     * @param {Deck} deck
+     * @param {number} hand_size
+     * @param {Uint32Array} target_ids
+     * @param {number} trials
+     * @param {bigint} seed
+     * @returns {SimulationResult}
+     */
+    simulate_openings(deck, hand_size, target_ids, trials, seed) {
+        _assertClass(deck, Deck);
+        const ptr0 = passArray32ToWasm0(target_ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_simulate_openings(this.__wbg_ptr, deck.__wbg_ptr, hand_size, ptr0, len0, trials, seed);
+        return SimulationResult.__wrap(ret);
+    }
+    /**
+     * Graph metrics of the deck graph built by `compute_links_within`.
      * @param {Uint32Array} pool_ids
-     * @returns {Link[]}
+     * @returns {DeckGraphStats}
+     */
+    compute_deck_stats(pool_ids) {
+        const ptr0 = passArray32ToWasm0(pool_ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_compute_deck_stats(this.__wbg_ptr, ptr0, len0);
+        return DeckGraphStats.__wrap(ret);
+    }
+    /**
+     * Find monsters that connect every source monster to every target monster.
+     * @param {Uint32Array} source
+     * @param {Uint32Array} target
+     * @returns {Bridges}
+     */
+    find_common_bridges(source, target) {
+        const ptr0 = passArray32ToWasm0(source, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArray32ToWasm0(target, wasm.__wbindgen_malloc);
+        const len1 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_find_common_bridges(this.__wbg_ptr, ptr0, len0, ptr1, len1);
+        return Bridges.__wrap(ret);
+    }
+    /**
+     * Replace the rule that decides when two monsters are connected, all following queries use it.
+     * @param {ConnectionRule} rule
+     */
+    set_connection_rule(rule) {
+        _assertClass(rule, ConnectionRule);
+        wasm.smallworldsearcher_set_connection_rule(this.__wbg_ptr, rule.__wbg_ptr);
+    }
+    /**
+     * For every monster in the pool, find to which other monsters from the pool it can link to, excluding self-links.
+     * @param {Uint32Array} pool_ids
+     * @returns {Links}
      */
     compute_links_within(pool_ids) {
         const ptr0 = passArray32ToWasm0(pool_ids, wasm.__wbindgen_malloc);
         const len0 = WASM_VECTOR_LEN;
         const ret = wasm.smallworldsearcher_compute_links_within(this.__wbg_ptr, ptr0, len0);
-        var v2 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
-        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
-        return v2;
+        return Links.__wrap(ret);
+    }
+    /**
+     * Precompute the neighborhood of every monster, all following queries look them up
+     * instead of recomputing them. Takes a few MB for the full card pool.
+     */
+    precompute_adjacency() {
+        wasm.smallworldsearcher_precompute_adjacency(this.__wbg_ptr);
     }
     /**
      * @param {Uint32Array} ids
-     * @returns {Monster[] | undefined}
+     * @returns {Bridges}
      */
     find_universal_bridges(ids) {
         const ptr0 = passArray32ToWasm0(ids, wasm.__wbindgen_malloc);
         const len0 = WASM_VECTOR_LEN;
         const ret = wasm.smallworldsearcher_find_universal_bridges(this.__wbg_ptr, ptr0, len0);
-        let v2;
-        if (ret[0] !== 0) {
-            v2 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
-            wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
-        }
-        return v2;
+        return Bridges.__wrap(ret);
+    }
+    /**
+     * @returns {boolean}
+     */
+    is_adjacency_precomputed() {
+        const ret = wasm.smallworldsearcher_is_adjacency_precomputed(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * Like `compute_links_within`, but symmetric links are merged and grouped by source/target pair.
+     * Each unordered pair appears once, with the monster that comes first in the card pool as source.
+     * @param {Uint32Array} pool_ids
+     * @returns {LinkGroups}
+     */
+    compute_link_groups_within(pool_ids) {
+        const ptr0 = passArray32ToWasm0(pool_ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_compute_link_groups_within(this.__wbg_ptr, ptr0, len0);
+        return LinkGroups.__wrap(ret);
     }
     /**
      * Given two monsters m1 and m2, lookup the first property that connects them.
-     * Returns a String or None if they are not connected.
+     * Returns a String or None if they are not connected, see `diagnose` for unknown passcodes.
      * @param {number} m1
      * @param {number} m2
      * @returns {string | undefined}
@@ -340,6 +2913,25 @@ export class SmallWorldSearcher {
         }
         return v1;
     }
+    /**
+     * Every monster of the card pool that matches the filter.
+     * @param {CardFilter} filter
+     * @returns {Monster[]}
+     */
+    search(filter) {
+        _assertClass(filter, CardFilter);
+        const ret = wasm.smallworldsearcher_search(this.__wbg_ptr, filter.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+    /**
+     * @returns {AliasTable}
+     */
+    aliases() {
+        const ret = wasm.smallworldsearcher_aliases(this.__wbg_ptr);
+        return AliasTable.__wrap(ret);
+    }
     /**
      * @returns {Monster[]}
      */
@@ -349,6 +2941,18 @@ export class SmallWorldSearcher {
         wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
         return v1;
     }
+    /**
+     * Classify the passcodes that would be ignored or resolved as alias by a query,
+     * e.g. to explain why a single card lookup returned nothing.
+     * @param {Uint32Array} ids
+     * @returns {Diagnostics}
+     */
+    diagnose(ids) {
+        const ptr0 = passArray32ToWasm0(ids, wasm.__wbindgen_malloc);
+        const len0 = WASM_VECTOR_LEN;
+        const ret = wasm.smallworldsearcher_diagnose(this.__wbg_ptr, ptr0, len0);
+        return Diagnostics.__wrap(ret);
+    }
     /**
      * @param {string} data
      */
@@ -356,7 +2960,10 @@ export class SmallWorldSearcher {
         const ptr0 = passStringToWasm0(data, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
         const len0 = WASM_VECTOR_LEN;
         const ret = wasm.smallworldsearcher_from_csv(ptr0, len0);
-        this.__wbg_ptr = ret >>> 0;
+        if (ret[2]) {
+            throw takeFromExternrefTable0(ret[1]);
+        }
+        this.__wbg_ptr = ret[0] >>> 0;
         SmallWorldSearcherFinalization.register(this, this.__wbg_ptr, this);
         return this;
     }
@@ -372,17 +2979,164 @@ export class SmallWorldSearcher {
 if (Symbol.dispose) SmallWorldSearcher.prototype[Symbol.dispose] = SmallWorldSearcher.prototype.free;
 
 /**
+ * A monster that can reach a target, together with the bridges that connect them.
+ */
+export class Source {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Source.prototype);
+        obj.__wbg_ptr = ptr;
+        SourceFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SourceFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_source_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    get source() {
+        const ret = wasm.chain_target(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {Uint32Array}
+     */
+    get bridges() {
+        const ret = wasm.source_bridges(this.__wbg_ptr);
+        var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) Source.prototype[Symbol.dispose] = Source.prototype.free;
+
+/**
+ * Every monster that can reach a target, ordered by passcode.
+ */
+export class Sources {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(Sources.prototype);
+        obj.__wbg_ptr = ptr;
+        SourcesFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SourcesFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_sources_free(ptr, 0);
+    }
+    /**
+     * passcodes of the query that were ignored or resolved as alias
+     * @returns {Diagnostics}
+     */
+    get diagnostics() {
+        const ret = wasm.sources_diagnostics(this.__wbg_ptr);
+        return Diagnostics.__wrap(ret);
+    }
+    /**
+     * @returns {Source[]}
+     */
+    get sources() {
+        const ret = wasm.sources_sources(this.__wbg_ptr);
+        var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+        wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+        return v1;
+    }
+}
+if (Symbol.dispose) Sources.prototype[Symbol.dispose] = Sources.prototype.free;
+
+/**
+ * How unknown ("?") ATK and DEF values are compared.
+ * @enum {0 | 1 | 2}
+ */
+export const UnknownStats = Object.freeze({
+    /**
+     * "?" counts as 0, the ruling for monsters in the Deck
+     */
+    AsZero: 0, "0": "AsZero",
+    /**
+     * "?" never shares the stat with any monster
+     */
+    NeverMatch: 1, "1": "NeverMatch",
+    /**
+     * "?" shares the stat with every other "?"
+     */
+    MatchEachOther: 2, "2": "MatchEachOther",
+});
+
+/**
+ * The CSV schema version of this build, a card database with an older version is stale
+ * @returns {number}
+ */
+export function csv_schema_version() {
+    const ret = wasm.csv_schema_version();
+    return ret >>> 0;
+}
+
+/**
+ * Decode a YDKE string, with `ignore_extra` the Extra Deck is left empty
  * @param {string} ydke
  * @param {boolean} ignore_extra
- * @returns {Uint32Array}
+ * @returns {Deck}
  */
 export function decode_ydke(ydke, ignore_extra) {
     const ptr0 = passStringToWasm0(ydke, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
     const len0 = WASM_VECTOR_LEN;
     const ret = wasm.decode_ydke(ptr0, len0, ignore_extra);
-    var v2 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
-    wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
-    return v2;
+    if (ret[2]) {
+        throw takeFromExternrefTable0(ret[1]);
+    }
+    return Deck.__wrap(ret[0]);
+}
+
+/**
+ * Decode a YDKE string, alternate artworks are replaced by their canonical passcode
+ * @param {string} ydke
+ * @param {AliasTable} aliases
+ * @returns {Deck}
+ */
+export function decode_ydke_with_aliases(ydke, aliases) {
+    const ptr0 = passStringToWasm0(ydke, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    _assertClass(aliases, AliasTable);
+    const ret = wasm.decode_ydke_with_aliases(ptr0, len0, aliases.__wbg_ptr);
+    if (ret[2]) {
+        throw takeFromExternrefTable0(ret[1]);
+    }
+    return Deck.__wrap(ret[0]);
+}
+
+/**
+ * Encode all sections of the deck into a YDKE string
+ * @param {Deck} deck
+ * @returns {string}
+ */
+export function encode_ydke(deck) {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+        _assertClass(deck, Deck);
+        const ret = wasm.encode_ydke(deck.__wbg_ptr);
+        deferred1_0 = ret[0];
+        deferred1_1 = ret[1];
+        return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+        wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
 }
 
 /**
@@ -406,17 +3160,31 @@ export function encode_ydke_main(ids) {
 }
 
 /**
+ * Parse a YDK file, cards are sorted into the sections by the `#main`, `#extra` and `!side` headers.
+ * With `ignore_extra` the Extra Deck is left empty.
  * @param {string} ydk
  * @param {boolean} ignore_extra
- * @returns {Uint32Array}
+ * @returns {Deck}
  */
 export function parse_ydk(ydk, ignore_extra) {
     const ptr0 = passStringToWasm0(ydk, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
     const len0 = WASM_VECTOR_LEN;
     const ret = wasm.parse_ydk(ptr0, len0, ignore_extra);
-    var v2 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
-    wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
-    return v2;
+    return Deck.__wrap(ret);
+}
+
+/**
+ * Parse a YDK file, alternate artworks are replaced by their canonical passcode
+ * @param {string} ydk
+ * @param {AliasTable} aliases
+ * @returns {Deck}
+ */
+export function parse_ydk_with_aliases(ydk, aliases) {
+    const ptr0 = passStringToWasm0(ydk, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    _assertClass(aliases, AliasTable);
+    const ret = wasm.parse_ydk_with_aliases(ptr0, len0, aliases.__wbg_ptr);
+    return Deck.__wrap(ret);
 }
 
 const EXPECTED_RESPONSE_TYPES = new Set(['basic', 'cors', 'default']);
@@ -454,9 +3222,49 @@ async function __wbg_load(module, imports) {
 function __wbg_get_imports() {
     const imports = {};
     imports.wbg = {};
+    imports.wbg.__wbg_Error_52673b7de5a0ca89 = function(arg0, arg1) {
+        const ret = Error(getStringFromWasm0(arg0, arg1));
+        return ret;
+    };
+    imports.wbg.__wbg___wbindgen_number_get_9619185a74197f95 = function(arg0, arg1) {
+        const obj = arg1;
+        const ret = typeof(obj) === 'number' ? obj : undefined;
+        getDataViewMemory0().setFloat64(arg0 + 8 * 1, isLikeNone(ret) ? 0 : ret, true);
+        getDataViewMemory0().setInt32(arg0 + 4 * 0, !isLikeNone(ret), true);
+    };
     imports.wbg.__wbg___wbindgen_throw_dd24417ed36fc46e = function(arg0, arg1) {
         throw new Error(getStringFromWasm0(arg0, arg1));
     };
+    imports.wbg.__wbg___wbindgen_try_into_number_9d33ffe037a9f5e5 = function(arg0) {
+        let result;
+        try { result = +arg0 } catch (e) { result = e }
+        const ret = result;
+        return ret;
+    };
+    imports.wbg.__wbg_chain_new = function(arg0) {
+        const ret = Chain.__wrap(arg0);
+        return ret;
+    };
+    imports.wbg.__wbg_csvwarning_new = function(arg0) {
+        const ret = CsvWarning.__wrap(arg0);
+        return ret;
+    };
+    imports.wbg.__wbg_deckchange_new = function(arg0) {
+        const ret = DeckChange.__wrap(arg0);
+        return ret;
+    };
+    imports.wbg.__wbg_deckissue_new = function(arg0) {
+        const ret = DeckIssue.__wrap(arg0);
+        return ret;
+    };
+    imports.wbg.__wbg_filtererror_new = function(arg0) {
+        const ret = FilterError.__wrap(arg0);
+        return ret;
+    };
+    imports.wbg.__wbg_ignoredid_new = function(arg0) {
+        const ret = IgnoredId.__wrap(arg0);
+        return ret;
+    };
     imports.wbg.__wbg_link_new = function(arg0) {
         const ret = Link.__wrap(arg0);
         return ret;
@@ -465,6 +3273,32 @@ function __wbg_get_imports() {
         const ret = Monster.__wrap(arg0);
         return ret;
     };
+    imports.wbg.__wbg_pathexplanation_new = function(arg0) {
+        const ret = PathExplanation.__wrap(arg0);
+        return ret;
+    };
+    imports.wbg.__wbg_pathgroup_new = function(arg0) {
+        const ret = PathGroup.__wrap(arg0);
+        return ret;
+    };
+    imports.wbg.__wbg_recommendation_new = function(arg0) {
+        const ret = Recommendation.__wrap(arg0);
+        return ret;
+    };
+    imports.wbg.__wbg_source_new = function(arg0) {
+        const ret = Source.__wrap(arg0);
+        return ret;
+    };
+    imports.wbg.__wbindgen_cast_2241b6af4c4b2941 = function(arg0, arg1) {
+        // Cast intrinsic for `Ref(String) -> Externref`.
+        const ret = getStringFromWasm0(arg0, arg1);
+        return ret;
+    };
+    imports.wbg.__wbindgen_cast_d6cd19b81560fd6e = function(arg0) {
+        // Cast intrinsic for `F64 -> Externref`.
+        const ret = arg0;
+        return ret;
+    };
     imports.wbg.__wbindgen_init_externref_table = function() {
         const table = wasm.__wbindgen_externrefs;
         const offset = table.grow(4);
@@ -482,6 +3316,7 @@ function __wbg_finalize_init(instance, module) {
     wasm = instance.exports;
     __wbg_init.__wbindgen_wasm_module = module;
     cachedDataViewMemory0 = null;
+    cachedFloat64ArrayMemory0 = null;
     cachedUint32ArrayMemory0 = null;
     cachedUint8ArrayMemory0 = null;
 
@@ -1,32 +1,244 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
+export const __wbg_bridges_free: (a: number, b: number) => void;
+export const __wbg_chain_free: (a: number, b: number) => void;
+export const __wbg_chains_free: (a: number, b: number) => void;
+export const __wbg_deckgraphstats_free: (a: number, b: number) => void;
+export const __wbg_filteredids_free: (a: number, b: number) => void;
+export const __wbg_handlines_free: (a: number, b: number) => void;
+export const __wbg_hop_free: (a: number, b: number) => void;
 export const __wbg_link_free: (a: number, b: number) => void;
+export const __wbg_linkgroups_free: (a: number, b: number) => void;
+export const __wbg_links_free: (a: number, b: number) => void;
+export const __wbg_pathexplanation_free: (a: number, b: number) => void;
+export const __wbg_pathgroup_free: (a: number, b: number) => void;
+export const __wbg_pathgroups_free: (a: number, b: number) => void;
+export const __wbg_recommendation_free: (a: number, b: number) => void;
+export const __wbg_recommendations_free: (a: number, b: number) => void;
 export const __wbg_smallworldsearcher_free: (a: number, b: number) => void;
+export const __wbg_source_free: (a: number, b: number) => void;
+export const __wbg_sources_free: (a: number, b: number) => void;
+export const bridges_diagnostics: (a: number) => number;
+export const bridges_monsters: (a: number) => [number, number];
+export const chain_activations: (a: number) => number;
+export const chain_path: (a: number) => [number, number];
+export const chain_target: (a: number) => number;
+export const chains_chains: (a: number) => [number, number];
+export const chains_diagnostics: (a: number) => number;
+export const deckgraphstats_bridge_share: (a: number) => [number, number];
+export const deckgraphstats_bridges: (a: number) => [number, number];
+export const deckgraphstats_component_count: (a: number) => number;
+export const deckgraphstats_components: (a: number) => [number, number];
+export const deckgraphstats_critical_bridges: (a: number) => [number, number];
+export const deckgraphstats_critical_pairs: (a: number) => [number, number];
+export const deckgraphstats_dead_ends: (a: number) => [number, number];
+export const deckgraphstats_degrees: (a: number) => [number, number];
+export const deckgraphstats_diagnostics: (a: number) => number;
+export const deckgraphstats_ids: (a: number) => [number, number];
+export const deckgraphstats_unreachable: (a: number) => [number, number];
+export const filteredids_diagnostics: (a: number) => number;
+export const filteredids_ids: (a: number) => [number, number];
+export const handlines_diagnostics: (a: number) => number;
+export const handlines_has_small_world: (a: number) => number;
+export const handlines_lines: (a: number) => [number, number];
+export const hop_properties: (a: number) => [number, number];
+export const hop_values: (a: number) => [number, number];
 export const link_bridge: (a: number) => number;
 export const link_start: (a: number) => number;
 export const link_target: (a: number) => number;
+export const linkgroups_bridge_offsets: (a: number) => [number, number];
+export const linkgroups_bridges: (a: number) => [number, number];
+export const linkgroups_diagnostics: (a: number) => number;
+export const linkgroups_is_empty: (a: number) => number;
+export const linkgroups_len: (a: number) => number;
+export const linkgroups_sources: (a: number) => [number, number];
+export const linkgroups_targets: (a: number) => [number, number];
+export const links_diagnostics: (a: number) => number;
+export const links_links: (a: number) => [number, number];
+export const pathexplanation_bridge: (a: number) => number;
+export const pathexplanation_bridge_to_target: (a: number) => number;
+export const pathexplanation_hand: (a: number) => number;
+export const pathexplanation_hand_to_bridge: (a: number) => number;
+export const pathexplanation_target: (a: number) => number;
+export const pathgroup_paths: (a: number) => [number, number];
+export const pathgroups_diagnostics: (a: number) => number;
+export const pathgroups_groups: (a: number) => [number, number];
+export const recommendation_connects: (a: number) => [number, number];
+export const recommendations_diagnostics: (a: number) => number;
+export const recommendations_recommendations: (a: number) => [number, number];
+export const smallworldsearcher_aliases: (a: number) => number;
+export const smallworldsearcher_canonical_id: (a: number, b: number) => number;
 export const smallworldsearcher_compute_connecting_property: (a: number, b: number, c: number) => [number, number];
-export const smallworldsearcher_compute_links_within: (a: number, b: number, c: number) => [number, number];
-export const smallworldsearcher_find_common_bridges: (a: number, b: number, c: number, d: number, e: number) => [number, number];
-export const smallworldsearcher_find_universal_bridges: (a: number, b: number, c: number) => [number, number];
-export const smallworldsearcher_from_csv: (a: number, b: number) => number;
+export const smallworldsearcher_compute_deck_stats: (a: number, b: number, c: number) => number;
+export const smallworldsearcher_compute_link_groups_within: (a: number, b: number, c: number) => number;
+export const smallworldsearcher_compute_links_within: (a: number, b: number, c: number) => number;
+export const smallworldsearcher_connection_rule: (a: number) => number;
+export const smallworldsearcher_diagnose: (a: number, b: number, c: number) => number;
+export const smallworldsearcher_explain_path: (a: number, b: number, c: number, d: number) => number;
+export const smallworldsearcher_filter_ids: (a: number, b: number, c: number, d: number) => number;
+export const smallworldsearcher_find_chains: (a: number, b: number, c: number, d: number, e: number) => number;
+export const smallworldsearcher_find_common_bridges: (a: number, b: number, c: number, d: number, e: number) => number;
+export const smallworldsearcher_find_hand_lines: (a: number, b: number, c: number, d: number) => number;
+export const smallworldsearcher_find_paths: (a: number, b: number, c: number, d: number, e: number) => number;
+export const smallworldsearcher_find_sources: (a: number, b: number, c: number, d: number) => number;
+export const smallworldsearcher_find_universal_bridges: (a: number, b: number, c: number) => number;
+export const smallworldsearcher_from_csv: (a: number, b: number) => [number, number, number];
+export const smallworldsearcher_from_csv_lenient: (a: number, b: number) => [number, number, number];
 export const smallworldsearcher_get_all: (a: number) => [number, number];
 export const smallworldsearcher_get_by_id: (a: number, b: number) => number;
-export const decode_ydke: (a: number, b: number, c: number) => [number, number];
-export const encode_ydke_main: (a: number, b: number) => [number, number];
-export const parse_ydk: (a: number, b: number, c: number) => [number, number];
+export const smallworldsearcher_is_adjacency_precomputed: (a: number) => number;
+export const smallworldsearcher_load_warnings: (a: number) => [number, number];
+export const smallworldsearcher_opening_odds: (a: number, b: number, c: number, d: number, e: number) => number;
+export const smallworldsearcher_optimize_deck: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => number;
+export const smallworldsearcher_parse_filter: (a: number, b: number, c: number) => [number, number, number];
+export const smallworldsearcher_precompute_adjacency: (a: number) => void;
+export const smallworldsearcher_recommend_bridges: (a: number, b: number, c: number, d: number) => number;
+export const smallworldsearcher_resolve_name: (a: number, b: number, c: number) => number;
+export const smallworldsearcher_schema_version: (a: number) => number;
+export const smallworldsearcher_search: (a: number, b: number) => [number, number];
+export const smallworldsearcher_search_names: (a: number, b: number, c: number, d: number) => [number, number];
+export const smallworldsearcher_select_bridges: (a: number, b: number, c: number, d: number) => number;
+export const smallworldsearcher_set_aliases: (a: number, b: number) => void;
+export const smallworldsearcher_set_connection_rule: (a: number, b: number) => void;
+export const smallworldsearcher_set_other_cards: (a: number, b: number, c: number) => void;
+export const smallworldsearcher_set_unknown_stats: (a: number, b: number) => void;
+export const smallworldsearcher_simulate_openings: (a: number, b: number, c: number, d: number, e: number, f: number, g: bigint) => number;
+export const smallworldsearcher_unknown_stats: (a: number) => number;
+export const source_bridges: (a: number) => [number, number];
+export const sources_diagnostics: (a: number) => number;
+export const sources_sources: (a: number) => [number, number];
+export const pathgroup_hand: (a: number) => number;
+export const pathgroup_target: (a: number) => number;
+export const recommendation_bridge: (a: number) => number;
+export const recommendation_new_connections: (a: number) => number;
+export const source_source: (a: number) => number;
+export const __wbg_connectionrule_free: (a: number, b: number) => void;
+export const __wbg_openingodds_free: (a: number, b: number) => void;
+export const connectionrule_at_least: (a: number, b: number, c: number) => number;
+export const connectionrule_at_most: (a: number, b: number, c: number) => number;
+export const connectionrule_exactly: (a: number, b: number, c: number) => number;
+export const connectionrule_small_world: () => number;
+export const openingodds_any_target: (a: number) => number;
+export const openingodds_diagnostics: (a: number) => number;
+export const openingodds_per_target: (a: number) => [number, number];
+export const openingodds_small_world: (a: number) => number;
+export const openingodds_targets: (a: number) => [number, number];
+export const __wbg_diagnostics_free: (a: number, b: number) => void;
+export const __wbg_ignoredid_free: (a: number, b: number) => void;
 export const __wbg_monster_free: (a: number, b: number) => void;
-export const monster_atk: (a: number) => number;
+export const diagnostics_dropped_ids: (a: number) => [number, number];
+export const diagnostics_ignored: (a: number) => [number, number];
+export const diagnostics_is_empty: (a: number) => number;
+export const ignoredid_canonical: (a: number) => number;
+export const ignoredid_id: (a: number) => number;
+export const ignoredid_message: (a: number) => [number, number];
+export const ignoredid_reason: (a: number) => number;
+export const monster_abilities_js: (a: number) => [number, number];
+export const monster_archetype: (a: number) => [number, number];
 export const monster_attribute_js: (a: number) => [number, number];
+export const monster_ban_status: (a: number) => number;
 export const monster_def: (a: number) => number;
+export const monster_frame: (a: number) => number;
 export const monster_id: (a: number) => number;
 export const monster_level: (a: number) => number;
 export const monster_name_js: (a: number) => [number, number];
+export const monster_release_date: (a: number) => [number, number];
+export const monster_scale: (a: number) => number;
 export const monster_type_js: (a: number) => [number, number];
+export const monster_atk: (a: number) => number;
+export const __wbg_aliastable_free: (a: number, b: number) => void;
+export const aliastable_canonical: (a: number, b: number) => number;
+export const aliastable_canonical_deck: (a: number, b: number) => number;
+export const aliastable_from_csv: (a: number, b: number) => [number, number, number];
+export const aliastable_is_alias: (a: number, b: number) => number;
+export const aliastable_is_empty: (a: number) => number;
+export const aliastable_len: (a: number) => number;
+export const aliastable_new: () => number;
+export const __wbg_csvwarning_free: (a: number, b: number) => void;
+export const csv_schema_version: () => number;
+export const csvwarning_column: (a: number) => [number, number];
+export const csvwarning_message: (a: number) => [number, number];
+export const csvwarning_row: (a: number) => number;
+export const csvwarning_skipped: (a: number) => number;
+export const csvwarning_value: (a: number) => [number, number];
+export const decode_ydke: (a: number, b: number, c: number) => [number, number, number];
+export const decode_ydke_with_aliases: (a: number, b: number, c: number) => [number, number, number];
+export const encode_ydke: (a: number) => [number, number];
+export const encode_ydke_main: (a: number, b: number) => [number, number];
+export const parse_ydk: (a: number, b: number, c: number) => number;
+export const parse_ydk_with_aliases: (a: number, b: number, c: number) => number;
+export const __wbg_deck_free: (a: number, b: number) => void;
+export const __wbg_deckchange_free: (a: number, b: number) => void;
+export const __wbg_deckissue_free: (a: number, b: number) => void;
+export const __wbg_gamestate_free: (a: number, b: number) => void;
+export const __wbg_get_optimizerconfig_budget: (a: number) => number;
+export const __wbg_get_optimizerconfig_hand_size: (a: number) => number;
+export const __wbg_get_optimizerconfig_iterations: (a: number) => number;
+export const __wbg_get_optimizerconfig_seed: (a: number) => bigint;
+export const __wbg_get_optimizerconfig_strategy: (a: number) => number;
+export const __wbg_optimizeddeck_free: (a: number, b: number) => void;
+export const __wbg_optimizerconfig_free: (a: number, b: number) => void;
+export const __wbg_set_optimizerconfig_budget: (a: number, b: number) => void;
+export const __wbg_set_optimizerconfig_hand_size: (a: number, b: number) => void;
+export const __wbg_set_optimizerconfig_iterations: (a: number, b: number) => void;
+export const __wbg_set_optimizerconfig_seed: (a: number, b: bigint) => void;
+export const __wbg_set_optimizerconfig_strategy: (a: number, b: number) => void;
+export const deck_add: (a: number, b: number, c: number, d: number) => void;
+export const deck_copies: (a: number, b: number) => number;
+export const deck_count: (a: number, b: number, c: number) => number;
+export const deck_counts: (a: number, b: number) => [number, number];
+export const deck_diff: (a: number, b: number) => [number, number];
+export const deck_ids: (a: number, b: number) => [number, number];
+export const deck_is_empty: (a: number) => number;
+export const deck_len: (a: number, b: number) => number;
+export const deck_new: () => number;
+export const deck_remove: (a: number, b: number, c: number, d: number) => number;
+export const deck_unique_ids: (a: number, b: number) => [number, number];
+export const deck_validate: (a: number) => [number, number];
+export const deckchange_from: (a: number) => number;
+export const deckchange_id: (a: number) => number;
+export const deckchange_section: (a: number) => number;
+export const deckchange_to: (a: number) => number;
+export const deckissue_id: (a: number) => number;
+export const deckissue_kind: (a: number) => number;
+export const deckissue_message: (a: number) => [number, number];
+export const gamestate_apply_small_world: (a: number, b: number, c: number, d: number, e: number) => [number, number];
+export const gamestate_banished: (a: number) => [number, number];
+export const gamestate_check_small_world: (a: number, b: number, c: number, d: number, e: number) => number;
+export const gamestate_deck: (a: number) => number;
+export const gamestate_draw: (a: number, b: number) => number;
+export const gamestate_graveyard: (a: number) => [number, number];
+export const gamestate_hand: (a: number) => [number, number];
+export const gamestate_lines: (a: number, b: number) => number;
+export const gamestate_new: (a: number, b: number, c: number, d: number) => number;
+export const optimizeddeck_coverage: (a: number) => number;
+export const optimizeddeck_deck: (a: number) => number;
+export const optimizeddeck_diagnostics: (a: number) => number;
+export const optimizeddeck_evaluations: (a: number) => number;
+export const optimizerconfig_new: (a: number, b: number) => number;
+export const deckissue_count: (a: number) => number;
+export const __wbg_cardfilter_free: (a: number, b: number) => void;
+export const __wbg_filtererror_free: (a: number, b: number) => void;
+export const cardfilter_is_empty: (a: number) => number;
+export const cardfilter_parse: (a: number, b: number) => [number, number, number];
+export const filtererror_message: (a: number) => [number, number];
+export const filtererror_position: (a: number) => number;
+export const __wbg_simulationresult_free: (a: number, b: number) => void;
+export const simulationresult_any_target: (a: number) => number;
+export const simulationresult_diagnostics: (a: number) => number;
+export const simulationresult_lower_bounds: (a: number) => [number, number];
+export const simulationresult_rates: (a: number) => [number, number];
+export const simulationresult_small_world: (a: number) => number;
+export const simulationresult_successes: (a: number) => [number, number];
+export const simulationresult_targets: (a: number) => [number, number];
+export const simulationresult_trials: (a: number) => number;
+export const simulationresult_upper_bounds: (a: number) => [number, number];
 export const __wbindgen_externrefs: WebAssembly.Table;
-export const __wbindgen_malloc: (a: number, b: number) => number;
-export const __externref_drop_slice: (a: number, b: number) => void;
 export const __wbindgen_free: (a: number, b: number, c: number) => void;
+export const __externref_drop_slice: (a: number, b: number) => void;
+export const __wbindgen_malloc: (a: number, b: number) => number;
 export const __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+export const __externref_table_dealloc: (a: number) => void;
+export const __externref_table_alloc: () => number;
 export const __wbindgen_start: () => void;